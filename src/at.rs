@@ -0,0 +1,776 @@
+//! Splitting a tuple at an index, by value, by reference or while pinned.
+//!
+//! Besides [split_tuple_at](crate::at::split_tuple_at), there are variants which return the halves in another shape, like [Parts](crate::Parts) or single
+//! elements, variants which split options, results or arrays of tuples, and, with the features `refs`, `nested`, `futures` and `alloc`, borrowed, nested,
+//! pinned and type-erased tuples.
+//!
+//! # Example
+//!
+//! ```rust
+//! #![feature(generic_const_exprs)]
+//!
+//! let t = Some((1, 1.0, "test"));
+//!
+//! let (l, r) = tuple_split::at::split_option_at::<2, _>(t);
+//! assert_eq!((l, r), (Some((1, 1.0)), Some(("test",))));
+//! ```
+
+use core::marker::PhantomData;
+#[cfg(feature = "futures")]
+use core::pin::Pin;
+
+use tupleops::{ConcatTuples, TupleConcat};
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+#[cfg(feature = "nested")]
+use crate::NestedSplit;
+#[cfg(feature = "alloc")]
+use crate::{private, SplitHalf, TupleErase};
+use crate::{
+    ConstUsize, Left, LeftRefs, Parts, Right, RightRefs, SplitCow, SplitInfo, Splittable, TupleLen, TupleRefs, TupleSplitAt, TupleSplitIntoRight, TupleUnwrapSingle
+};
+#[cfg(feature = "refs")]
+use crate::{LeftMuts, RightMuts, SplitIndex, TupleSplitAtRef};
+#[cfg(feature = "futures")]
+use crate::{TupleFutures, TupleSplitAtPin};
+
+/// Splits tuple at a given index.
+///
+/// Index is specified as const generic `MIDDLE.
+///
+/// Tuple must be of trait `[TupleSplitAt](crate::TupleSplitAt)<MIDDLE>`. With the feature `array_split`, arrays can be split too, into two arrays.
+/// See [Splittable](crate::Splittable).
+///
+/// Returns `([SplitTypeAt::Left](SplitTypeAt::Left), [SplitTypeAt::Right](SplitTypeAt::Right))` for the given Tuple and `MIDDLE`.
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = (1, 1.0, "test");
+///
+/// let (l, r) = tuple_split::split_tuple_at::<2, _>(t);
+///
+/// assert_eq!(t, tupleops::concat_tuples(l, r));
+/// ```
+///
+/// # Drop order
+///
+/// Splitting only moves the elements, so nothing is dropped during the split. A half which is discarded, like with `let (_, r) = ...`, is dropped as a tuple,
+/// so its elements are dropped front to back, in the same order they would have been dropped in the original tuple.
+pub const fn split_tuple_at<const MIDDLE: usize, T>(tuple: T) -> (T::Left, T::Right)
+where
+    T: ~const Splittable<MIDDLE>
+{
+    tuple.split()
+}
+
+/// Splits tuple at a given index, like [split_tuple_at](crate::split_tuple_at), but returns the halves as [Parts](crate::Parts).
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = (1, 1.0, "test");
+///
+/// let parts = tuple_split::split_tuple_at_parts::<2, _>(t);
+///
+/// assert_eq!(parts.into_parts(), ((1, 1.0), ("test",)));
+/// ```
+pub const fn split_tuple_at_parts<const MIDDLE: usize, T>(tuple: T) -> Parts<T::Left, T::Right>
+where
+    T: ~const TupleSplitAt<MIDDLE>
+{
+    let (left, right) = tuple.split_tuple_at();
+    Parts::from_parts(left, right)
+}
+
+/// Splits tuple at a given index, like [split_tuple_at](crate::split_tuple_at), and writes both halves to `out` with [Debug](core::fmt::Debug) formatting.
+///
+/// Meant for a quick look at the halves during development. It only needs [core::fmt::Write], so it works without `std`.
+/// Formatting errors are ignored, since the split itself can't fail.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let mut out = String::new();
+///
+/// let (l, r) = tuple_split::debug_split::<2, _>((1, 1.0, "test"), &mut out);
+///
+/// assert_eq!(out, "left: (1, 1.0), right: (\"test\",)\n");
+/// assert_eq!(l, (1, 1.0));
+/// assert_eq!(r, ("test",));
+/// ```
+pub fn debug_split<const MIDDLE: usize, T>(tuple: T, out: &mut dyn core::fmt::Write) -> (T::Left, T::Right)
+where
+    T: TupleSplitAt<MIDDLE, Left: core::fmt::Debug, Right: core::fmt::Debug>
+{
+    let (left, right) = tuple.split_tuple_at();
+    let _ = writeln!(out, "left: {left:?}, right: {right:?}");
+    (left, right)
+}
+
+/// Splits tuple at a given index, and asserts at compile time that the halves are `L` and `R`.
+///
+/// Works like [split_tuple_at](crate::split_tuple_at), but if the tuple's shape has drifted, like after reordering its elements,
+/// it fails to compile instead of quietly returning different halves.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = (1u8, 1.0f32, "test");
+///
+/// let (l, r) = tuple_split::split_at_typed::<2, (u8, f32), (&str,), _>(t);
+///
+/// assert_eq!(t, tupleops::concat_tuples(l, r));
+/// ```
+///
+/// ```rust,compile_fail,E0271
+/// #![feature(generic_const_exprs)]
+///
+/// let t = (1.0f32, 1u8, "test");
+///
+/// let (l, r) = tuple_split::split_at_typed::<2, (u8, f32), (&str,), _>(t);
+/// ```
+pub const fn split_at_typed<const MIDDLE: usize, L, R, T>(tuple: T) -> (L, R)
+where
+    T: ~const TupleSplitAt<MIDDLE, Left = L, Right = R>
+{
+    tuple.split_tuple_at()
+}
+
+/// Splits tuple at a given index, and unwraps each half that has exactly one element, using [TupleUnwrapSingle](crate::TupleUnwrapSingle).
+///
+/// This saves having to destructure `(x,)` when splitting off a single element. Halves of any other length stay tuples.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = (1, 1.0, "test");
+///
+/// let (l, r) = tuple_split::split_at_unwrap_single::<2, _>(t);
+///
+/// assert_eq!(l, (1, 1.0));
+/// assert_eq!(r, "test");
+/// ```
+pub const fn split_at_unwrap_single<const MIDDLE: usize, T>(tuple: T) -> (<T::Left as TupleUnwrapSingle>::Unwrapped, <T::Right as TupleUnwrapSingle>::Unwrapped)
+where
+    T: ~const TupleSplitAt<MIDDLE, Left: ~const TupleUnwrapSingle, Right: ~const TupleUnwrapSingle>
+{
+    let (left, right) = tuple.split_tuple_at();
+    (left.unwrap_single(), right.unwrap_single())
+}
+
+/// Splits tuple at the index carried by the type-level integer `P`.
+///
+/// Equivalent to [split_tuple_at](crate::split_tuple_at)`::<{ P::VALUE }, _>`, but with the index given as a type instead of a literal const generic.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// use tuple_split::ConstUsize;
+///
+/// struct Two;
+///
+/// impl ConstUsize for Two
+/// {
+///     const VALUE: usize = 2;
+/// }
+///
+/// let t = (1, 1.0, "test");
+///
+/// let (l, r) = tuple_split::split_tuple_at_const::<Two, _>(t);
+///
+/// assert_eq!(l, (1, 1.0));
+/// assert_eq!(r, ("test",));
+/// ```
+pub const fn split_tuple_at_const<P, T>(tuple: T) -> (Left<T, { P::VALUE }>, Right<T, { P::VALUE }>)
+where
+    P: ConstUsize,
+    T: ~const TupleSplitAt<{ P::VALUE }>
+{
+    tuple.split_tuple_at()
+}
+
+/// Splits tuple at the index given by the length of another tuple type `L`.
+///
+/// Only the number of elements in `L` matters, not their types. Unlike [split_tuple_into_left](crate::split_tuple_into_left), the left part doesn't have to be `L`.
+/// This is handy for saying "split where this other tuple ends".
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = (1, 1.0, "test");
+///
+/// let (l, r) = tuple_split::split_at_type_len::<((), ()), _>(t);
+///
+/// assert_eq!(l, (1, 1.0));
+/// assert_eq!(r, ("test",));
+/// ```
+pub const fn split_at_type_len<L, T>(tuple: T) -> (Left<T, { L::LEN }>, Right<T, { L::LEN }>)
+where
+    L: TupleLen,
+    T: ~const TupleSplitAt<{ L::LEN }>
+{
+    tuple.split_tuple_at()
+}
+
+/// Splits tuple at a given index, and returns the halves in a [SplitInfo](crate::SplitInfo), which also gives their lengths.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = (1, 1.0, "test");
+///
+/// type Info = tuple_split::SplitInfo<(i32, f64), (&'static str,)>;
+///
+/// let info: Info = tuple_split::split_tuple_at_info::<2, _>(t);
+///
+/// assert_eq!(info.left, (1, 1.0));
+/// assert_eq!(info.right, ("test",));
+/// assert_eq!((Info::LEFT_LEN, Info::RIGHT_LEN, Info::TOTAL_LEN), (2, 1, 3));
+/// ```
+pub const fn split_tuple_at_info<const MIDDLE: usize, T>(tuple: T) -> SplitInfo<T::Left, T::Right>
+where
+    T: ~const TupleSplitAt<MIDDLE>
+{
+    let (left, right) = tuple.split_tuple_at();
+    SplitInfo { left, right }
+}
+
+/// Splits two parallel tuples at the same index, and groups the left halves together and the right halves together.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let (l, r) = tuple_split::zip_split::<1, _, _>((1, 2, 3), ("a", "b", "c"));
+///
+/// assert_eq!(l, ((1,), ("a",)));
+/// assert_eq!(r, ((2, 3), ("b", "c")));
+/// ```
+#[allow(clippy::type_complexity)]
+pub const fn zip_split<const MIDDLE: usize, A, B>(a: A, b: B) -> ((A::Left, B::Left), (A::Right, B::Right))
+where
+    A: ~const TupleSplitAt<MIDDLE>,
+    B: ~const TupleSplitAt<MIDDLE>
+{
+    let (a_left, a_right) = a.split_tuple_at();
+    let (b_left, b_right) = b.split_tuple_at();
+    ((a_left, b_left), (a_right, b_right))
+}
+
+/// Splits three parallel tuples at the same index, and groups the left halves together and the right halves together.
+///
+/// This is handy for structure-of-arrays data held as several tuples of the same length.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let (l, r) = tuple_split::zip_split3::<1, _, _, _>((1, 2, 3), ("a", "b", "c"), (1.0, 2.0, 3.0));
+///
+/// assert_eq!(l, ((1,), ("a",), (1.0,)));
+/// assert_eq!(r, ((2, 3), ("b", "c"), (2.0, 3.0)));
+/// ```
+#[allow(clippy::type_complexity)]
+pub const fn zip_split3<const MIDDLE: usize, A, B, C>(a: A, b: B, c: C) -> ((A::Left, B::Left, C::Left), (A::Right, B::Right, C::Right))
+where
+    A: ~const TupleSplitAt<MIDDLE>,
+    B: ~const TupleSplitAt<MIDDLE>,
+    C: ~const TupleSplitAt<MIDDLE>
+{
+    let (a_left, a_right) = a.split_tuple_at();
+    let (b_left, b_right) = b.split_tuple_at();
+    let (c_left, c_right) = c.split_tuple_at();
+    ((a_left, b_left, c_left), (a_right, b_right, c_right))
+}
+
+/// Splits an optional tuple at a given index, into two optional halves.
+///
+/// `Some(tuple)` is split into `(Some(left), Some(right))`, and `None` becomes `(None, None)`.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = Some((1, 1.0, "test"));
+///
+/// let (l, r) = tuple_split::split_option_at::<2, _>(t);
+///
+/// assert_eq!(l, Some((1, 1.0)));
+/// assert_eq!(r, Some(("test",)));
+/// ```
+pub const fn split_option_at<const MIDDLE: usize, T>(option: Option<T>) -> (Option<T::Left>, Option<T::Right>)
+where
+    T: ~const TupleSplitAt<MIDDLE>
+{
+    match option
+    {
+        Some(tuple) =>
+        {
+            let (left, right) = tuple.split_tuple_at();
+            (Some(left), Some(right))
+        }
+        None => (None, None)
+    }
+}
+
+/// Splits the tuple in a [Result](core::result::Result) at a given index, passing through the error.
+///
+/// `Ok(tuple)` is split into `Ok((left, right))`, and `Err(error)` stays `Err(error)`.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t: Result<_, ()> = Ok((1, 1.0, "test"));
+///
+/// let (l, r) = tuple_split::split_result_at::<2, _, _>(t).unwrap();
+///
+/// assert_eq!(l, (1, 1.0));
+/// assert_eq!(r, ("test",));
+/// ```
+pub const fn split_result_at<const MIDDLE: usize, T, E>(result: Result<T, E>) -> Result<(T::Left, T::Right), E>
+where
+    T: ~const TupleSplitAt<MIDDLE>
+{
+    match result
+    {
+        Ok(tuple) => Ok(tuple.split_tuple_at()),
+        Err(error) => Err(error)
+    }
+}
+
+/// Splits each tuple in an array at a given index, returning an array of the left halves and an array of the right halves.
+///
+/// This is useful for turning an array of tuples into a structure of arrays. The halves keep the order of the tuples they came from.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let a = [(1, 1.0, "one"), (2, 2.0, "two")];
+///
+/// let (l, r) = tuple_split::split_array_of_tuples_at::<1, _, 2>(a);
+///
+/// assert_eq!(l, [(1,), (2,)]);
+/// assert_eq!(r, [(1.0, "one"), (2.0, "two")]);
+/// ```
+pub fn split_array_of_tuples_at<const MIDDLE: usize, T, const N: usize>(array: [T; N]) -> ([T::Left; N], [T::Right; N])
+where
+    T: TupleSplitAt<MIDDLE>
+{
+    let mut halves = array.map(|tuple| {
+        let (left, right) = tuple.split_tuple_at();
+        (Some(left), Some(right))
+    });
+    let lefts = core::array::from_fn(|i| halves[i].0.take().unwrap());
+    let rights = core::array::from_fn(|i| halves[i].1.take().unwrap());
+    (lefts, rights)
+}
+
+/// Splits tuple in the middle.
+///
+/// If the tuple has an odd number of elements, the right half gets the extra element.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = (1, 1.0, "test");
+///
+/// let (l, r) = tuple_split::split_halves(t);
+///
+/// assert_eq!(l, (1,));
+/// assert_eq!(r, (1.0, "test"));
+/// ```
+pub const fn split_halves<T>(tuple: T) -> (Left<T, { T::LEN / 2 }>, Right<T, { T::LEN / 2 }>)
+where
+    T: TupleLen + ~const TupleSplitAt<{ T::LEN / 2 }>
+{
+    tuple.split_tuple_at()
+}
+
+/// Splits a borrowed tuple at a given index, into two tuples of references to its elements.
+///
+/// Note that the halves are tuples of references, like `(&A, &B)`, and not references to tuples, like `&(A, B)`.
+/// The compiler is free to lay out the elements of a tuple in any order, so a tuple `(A, B, C)` does not contain an `(A, B)` anywhere in memory to point to.
+/// There's no sound way to borrow a half as a sub-tuple, so this is the only way to split a tuple without moving it.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = (1, 1.0, "test");
+///
+/// let (l, r) = tuple_split::split_tuple_at_ref::<2, _>(&t);
+///
+/// assert_eq!(l, (&1, &1.0));
+/// assert_eq!(r, (&"test",));
+/// ```
+#[cfg(feature = "refs")]
+pub fn split_tuple_at_ref<const MIDDLE: usize, T>(tuple: &T) -> (LeftRefs<'_, T, MIDDLE>, RightRefs<'_, T, MIDDLE>)
+where
+    T: TupleSplitAtRef<MIDDLE>
+{
+    tuple.split_tuple_at_ref_lt()
+}
+
+/// Splits a borrowed tuple at a given index, like [split_tuple_at_ref](crate::split_tuple_at_ref), and also returns a [SplitIndex](crate::SplitIndex)
+/// as evidence of where it was split.
+///
+/// The evidence can be used to split the owned tuple at the same index later, which is handy for APIs that first inspect a tuple and then consume it.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = (1, 1.0, "test");
+///
+/// let (l, r, index) = tuple_split::split_ref_with_proof::<2, _>(&t);
+/// assert_eq!(l, (&1, &1.0));
+/// assert_eq!(r, (&"test",));
+///
+/// let (l, r) = index.split_tuple_at(t);
+/// assert_eq!(l, (1, 1.0));
+/// assert_eq!(r, ("test",));
+/// ```
+#[cfg(feature = "refs")]
+pub fn split_ref_with_proof<const MIDDLE: usize, T>(tuple: &T) -> (LeftRefs<'_, T, MIDDLE>, RightRefs<'_, T, MIDDLE>, SplitIndex<MIDDLE>)
+where
+    T: TupleSplitAtRef<MIDDLE>
+{
+    let (left, right) = tuple.split_tuple_at_ref_lt();
+    (left, right, SplitIndex)
+}
+
+/// Peeks at the halves of a borrowed tuple split at a given index. The first step of the peek-then-commit pattern, followed by [split_commit](crate::split_commit).
+///
+/// Peek at the halves to decide what to do, and then commit to an owning split of the same tuple at the same index.
+/// The two functions take the same index and bounds, so a tuple that can be peeked at can always be committed.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = (1, 1.0, "test");
+///
+/// let (l, _) = tuple_split::split_peek::<1, _>(&t);
+/// if *l.0 > 0
+/// {
+///     let (l, r) = tuple_split::split_commit::<1, _>(t);
+///
+///     assert_eq!(l, (1,));
+///     assert_eq!(r, (1.0, "test"));
+/// }
+/// ```
+#[cfg(feature = "refs")]
+pub fn split_peek<const MIDDLE: usize, T>(tuple: &T) -> (LeftRefs<'_, T, MIDDLE>, RightRefs<'_, T, MIDDLE>)
+where
+    T: TupleSplitAtRef<MIDDLE>
+{
+    tuple.split_tuple_at_ref_lt()
+}
+
+/// Splits tuple at a given index, after peeking at it with [split_peek](crate::split_peek). The second step of the peek-then-commit pattern.
+///
+/// Equivalent to [split_tuple_at](crate::split_tuple_at), but with the same bounds as [split_peek](crate::split_peek).
+#[cfg(feature = "refs")]
+pub fn split_commit<const MIDDLE: usize, T>(tuple: T) -> (Left<T, MIDDLE>, Right<T, MIDDLE>)
+where
+    T: TupleSplitAtRef<MIDDLE>
+{
+    tuple.split_tuple_at()
+}
+
+/// Splits a borrowed tuple at a given index, into two tuples of references to its elements, where each half has its own lifetime.
+///
+/// With [split_tuple_at_ref](crate::split_tuple_at_ref), both halves share one lifetime. In generic code, the compiler can't shorten just one of them,
+/// since it can't see that the halves are covariant in it. Here the lifetimes `'l` and `'r` are chosen independently, as long as the borrow of the tuple outlives both.
+///
+/// See [TupleSplitAtRef::split_tuple_at_ref_lt](TupleSplitAtRef::split_tuple_at_ref_lt) for why this is sound.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// use tuple_split::{LeftRefs, TupleSplitAtRef};
+///
+/// fn first<'a, T>(t: &'a T) -> LeftRefs<'a, T, 1>
+/// where
+///     T: TupleSplitAtRef<1>
+/// {
+///     let (l, r) = tuple_split::split_tuple_at_ref_lt::<'a, 'a, '_, 1, T>(t);
+///     drop(r);
+///     l
+/// }
+///
+/// assert_eq!(first(&(1, 1.0, "test")), (&1,));
+/// ```
+#[cfg(feature = "refs")]
+pub fn split_tuple_at_ref_lt<'a, 'l, 'r, const MIDDLE: usize, T>(tuple: &'a T) -> (LeftRefs<'l, T, MIDDLE>, RightRefs<'r, T, MIDDLE>)
+where
+    'a: 'l + 'r,
+    T: TupleSplitAtRef<MIDDLE>
+{
+    tuple.split_tuple_at_ref_lt()
+}
+
+/// Splits a mutably borrowed tuple at a given index, into two tuples of mutable references to its elements.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let mut t = (1, 1.0, "test");
+///
+/// let ((a, b), (c,)) = tuple_split::split_tuple_at_mut::<2, _>(&mut t);
+/// *a += 1;
+/// *b *= 2.0;
+/// *c = "mutated";
+///
+/// assert_eq!(t, (2, 2.0, "mutated"));
+/// ```
+#[cfg(feature = "refs")]
+pub fn split_tuple_at_mut<const MIDDLE: usize, T>(tuple: &mut T) -> (LeftMuts<'_, T, MIDDLE>, RightMuts<'_, T, MIDDLE>)
+where
+    T: TupleSplitAtRef<MIDDLE>
+{
+    tuple.split_tuple_at_mut()
+}
+
+/// Splits a mutably borrowed tuple at a given index, like [split_tuple_at_mut](crate::split_tuple_at_mut), but with the halves swapped.
+///
+/// The mutable references to the right half come first, followed by the ones to the left half, so the tail of the tuple can be processed before its head,
+/// without moving anything.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let mut t = (1, 1.0, "test");
+///
+/// let ((c,), (a, b)) = tuple_split::swap_and_split_mut::<2, _>(&mut t);
+/// *c = "mutated";
+/// *a += 1;
+/// *b *= 2.0;
+///
+/// assert_eq!(t, (2, 2.0, "mutated"));
+/// ```
+#[cfg(feature = "refs")]
+pub fn swap_and_split_mut<const MIDDLE: usize, T>(tuple: &mut T) -> (RightMuts<'_, T, MIDDLE>, LeftMuts<'_, T, MIDDLE>)
+where
+    T: TupleSplitAtRef<MIDDLE>
+{
+    let (left, right) = tuple.split_tuple_at_mut();
+    (right, left)
+}
+
+/// Splits an owned or borrowed tuple at a given index, into two halves which are owned or borrowed accordingly.
+///
+/// An owned tuple is split into owned halves, like with [split_tuple_at](crate::split_tuple_at), and a borrowed tuple is split into halves of references,
+/// like with [split_tuple_at_ref](crate::split_tuple_at_ref). This is useful for code which is generic over whether it owns the tuple.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// use tuple_split::SplitCow;
+///
+/// let t = (1, 1.0, "test");
+///
+/// let (l, r) = tuple_split::split_tuple_at_cow::<2, _>(SplitCow::Owned(t));
+/// assert_eq!(l, SplitCow::Owned((1, 1.0)));
+/// assert_eq!(r, SplitCow::Owned(("test",)));
+///
+/// let (l, r) = tuple_split::split_tuple_at_cow::<2, _>(SplitCow::borrowed(&t));
+/// assert_eq!(l, SplitCow::Borrowed((&1, &1.0)));
+/// assert_eq!(r, SplitCow::Borrowed((&"test",)));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn split_tuple_at_cow<'a, const MIDDLE: usize, T>(tuple: SplitCow<'a, T>) -> (SplitCow<'a, T::Left>, SplitCow<'a, T::Right>)
+where
+    T: TupleSplitAt<MIDDLE, Left: TupleRefs, Right: TupleRefs>
+        + TupleRefs<Refs<'a>: TupleSplitAt<MIDDLE, Left = LeftRefs<'a, T, MIDDLE>, Right = RightRefs<'a, T, MIDDLE>>>
+        + 'a
+{
+    match tuple
+    {
+        SplitCow::Owned(tuple) =>
+        {
+            let (left, right) = tuple.split_tuple_at();
+            (SplitCow::Owned(left), SplitCow::Owned(right))
+        }
+        SplitCow::Borrowed(refs) =>
+        {
+            let (left, right) = refs.split_tuple_at();
+            (SplitCow::Borrowed(left), SplitCow::Borrowed(right))
+        }
+    }
+}
+
+/// Splits a pinned tuple at a given index, into two tuples of pinned mutable references to its elements.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// use core::pin::pin;
+///
+/// let mut t = pin!((1, 1.0, "test"));
+///
+/// let ((a, b), (c,)) = tuple_split::split_tuple_at_pin::<2, _>(t.as_mut());
+/// *a.get_mut() += 1;
+///
+/// assert_eq!(*t, (2, 1.0, "test"));
+/// ```
+#[cfg(feature = "futures")]
+pub fn split_tuple_at_pin<const MIDDLE: usize, T>(tuple: Pin<&mut T>) -> (T::LeftPins<'_>, T::RightPins<'_>)
+where
+    T: TupleSplitAtPin<MIDDLE>
+{
+    tuple.split_tuple_at_pin()
+}
+
+/// Splits a pinned tuple of futures at a given index, into two tuples of pinned futures.
+///
+/// This lets an executor-style combinator poll the two groups of futures separately, for example to prioritize one group, or to stop polling one of them early.
+/// The futures don't have to be [Unpin](core::marker::Unpin), since they stay pinned in place. See [TupleSplitAtPin](crate::TupleSplitAtPin) for why this is sound.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// use core::{future::{self, Future}, pin::pin, task::{Context, Poll, Waker}};
+///
+/// let mut futures = pin!((future::ready(1), future::ready(1.0), future::pending::<()>()));
+/// let mut cx = Context::from_waker(Waker::noop());
+///
+/// let ((a, b), (c,)) = tuple_split::split_pinned_futures_at::<2, _>(futures.as_mut());
+///
+/// assert_eq!(a.poll(&mut cx), Poll::Ready(1));
+/// assert_eq!(b.poll(&mut cx), Poll::Ready(1.0));
+/// assert_eq!(c.poll(&mut cx), Poll::Pending);
+/// ```
+#[cfg(feature = "futures")]
+pub fn split_pinned_futures_at<const MIDDLE: usize, T>(futures: Pin<&mut T>) -> (T::LeftPins<'_>, T::RightPins<'_>)
+where
+    T: TupleFutures + TupleSplitAtPin<MIDDLE>
+{
+    futures.split_tuple_at_pin()
+}
+
+/// Splits a tuple ending in a [PhantomData](core::marker::PhantomData) tag at a given index, so that both halves end in the tag.
+///
+/// The right half keeps the original tag, and a new one is appended to the left half. This lets type-state encoded as a trailing tag survive the split.
+/// `MIDDLE` may be anywhere from `0` up to and including the index of the tag.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// use core::marker::PhantomData;
+///
+/// struct Open;
+///
+/// let t = (1, 1.0, "test", PhantomData::<Open>);
+///
+/// let (l, r) = tuple_split::split_tagged_at::<2, Open, _>(t);
+///
+/// let _: (i32, f64, PhantomData<Open>) = l;
+/// let _: (&str, PhantomData<Open>) = r;
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn split_tagged_at<const MIDDLE: usize, Tag, T>(tuple: T) -> (ConcatTuples<T::Left, (PhantomData<Tag>,)>, T::Right)
+where
+    T: TupleSplitAt<MIDDLE, Right: TupleSplitIntoRight<(PhantomData<Tag>,)>>,
+    (T::Left, (PhantomData<Tag>,)): TupleConcat<T::Left, (PhantomData<Tag>,)>
+{
+    let (left, right) = tuple.split_tuple_at();
+    (tupleops::concat_tuples(left, (PhantomData,)), right)
+}
+
+/// Splits a nested tuple at the flat index `INDEX` of the logical tuple it represents. See [NestedSplit](crate::NestedSplit).
+///
+/// This allows working with logical tuples longer than the maximum supported tuple size, without enabling a bigger size feature and paying for the
+/// implementations that come with it.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = ((1, 2), (3, 4, 5));
+///
+/// let (l, r) = tuple_split::split_nested_at::<1, _>(t);
+///
+/// assert_eq!(l, ((1,),));
+/// assert_eq!(r, ((2,), (3, 4, 5)));
+/// ```
+#[cfg(feature = "nested")]
+pub fn split_nested_at<const INDEX: usize, T>(tuple: T) -> (T::Left, T::Right)
+where
+    T: NestedSplit<INDEX>
+{
+    tuple.split_nested()
+}
+
+/// Splits tuple at an index only known at runtime, into two halves with their element types erased.
+///
+/// Every element must implement [Debug](core::fmt::Debug) and be `'static`, see [TupleErase](crate::TupleErase).
+/// Returns [None](Option::None) if `index` is past the end of the tuple.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let (l, r) = tuple_split::split_erased_at((1, 1.0, "test"), 2).unwrap();
+///
+/// assert_eq!((l.len(), r.len()), (2, 1));
+/// assert_eq!(format!("{l:?}"), "(1, 1.0)");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn split_erased_at<T>(tuple: T, index: usize) -> Option<(Box<dyn SplitHalf>, Box<dyn SplitHalf>)>
+where
+    T: TupleErase
+{
+    let mut left = tuple.erase();
+    if index > left.len()
+    {
+        return None;
+    }
+    let right = left.split_off(index);
+    Some((Box::new(private::ErasedHalf(left)), Box::new(private::ErasedHalf(right))))
+}
@@ -0,0 +1,155 @@
+//! Splitting elements off either end of a tuple.
+//!
+//! This has [split_first](crate::ends::split_first) and [split_last](crate::ends::split_last) for single elements, and splits after the leading elements
+//! which match a predicate, like [take_while_matching](crate::ends::take_while_matching).
+//!
+//! # Example
+//!
+//! ```rust
+//! #![feature(generic_const_exprs)]
+//!
+//! let t = (1, 1.0, "test");
+//!
+//! let (first, rest) = tuple_split::ends::split_first::<u8, _>(t);
+//! assert_eq!((first, rest), (1, (1.0, "test")));
+//! ```
+
+use crate::{Left, Right, TupleFindFirst, TupleLen, TupleReverse, TupleSplitAt, TupleTakeWhile};
+
+/// Splits off the longest leading run of elements whose types match the [ElementPredicate](crate::ElementPredicate) `P`.
+///
+/// Returns the matching prefix and the rest of the tuple.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// use tuple_split::ElementPredicate;
+///
+/// struct IsInteger;
+///
+/// impl ElementPredicate<u8> for IsInteger
+/// {
+///     const MATCHES: bool = true;
+/// }
+/// impl ElementPredicate<i32> for IsInteger
+/// {
+///     const MATCHES: bool = true;
+/// }
+/// impl ElementPredicate<&str> for IsInteger
+/// {
+///     const MATCHES: bool = false;
+/// }
+///
+/// let t = (1u8, 2i32, "three", 4u8);
+///
+/// let (l, r) = tuple_split::take_while_matching::<IsInteger, _>(t);
+///
+/// assert_eq!(l, (1, 2));
+/// assert_eq!(r, ("three", 4));
+/// ```
+pub const fn take_while_matching<P, T>(tuple: T) -> (Left<T, { <T as TupleTakeWhile<P>>::COUNT }>, Right<T, { <T as TupleTakeWhile<P>>::COUNT }>)
+where
+    T: TupleTakeWhile<P> + ~const TupleSplitAt<{ <T as TupleTakeWhile<P>>::COUNT }>
+{
+    tuple.split_tuple_at()
+}
+
+/// Splits tuple at the first element which matches the [PositionPredicate](crate::PositionPredicate) `P`, so that it's the first element of the right half.
+///
+/// If no element matches, the right half is empty.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// use tuple_split::PositionPredicate;
+///
+/// struct AtLeastTwo;
+///
+/// impl<T, const INDEX: usize> PositionPredicate<T, INDEX> for AtLeastTwo
+/// {
+///     const MATCHES: bool = INDEX >= 2;
+/// }
+///
+/// let t = (1, 1.0, "test", 'c');
+///
+/// let (l, r) = tuple_split::split_at_first::<AtLeastTwo, _>(t);
+///
+/// assert_eq!(l, (1, 1.0));
+/// assert_eq!(r, ("test", 'c'));
+/// ```
+pub const fn split_at_first<P, T>(tuple: T) -> (Left<T, { <T as TupleFindFirst<P>>::INDEX }>, Right<T, { <T as TupleFindFirst<P>>::INDEX }>)
+where
+    T: TupleFindFirst<P> + ~const TupleSplitAt<{ <T as TupleFindFirst<P>>::INDEX }>
+{
+    tuple.split_tuple_at()
+}
+
+/// Splits off the first element of a tuple, returning it along with the rest of the tuple.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let (first, rest) = tuple_split::split_first((1, 1.0, "test"));
+///
+/// assert_eq!(first, 1);
+/// assert_eq!(rest, (1.0, "test"));
+/// ```
+pub const fn split_first<F, T>(tuple: T) -> (F, T::Right)
+where
+    T: ~const TupleSplitAt<1, Left = (F,)>
+{
+    let ((first,), rest) = tuple.split_tuple_at();
+    (first, rest)
+}
+
+/// Splits off the last element of a tuple, returning the rest of the tuple along with it.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let (rest, last) = tuple_split::split_last((1, 1.0, "test"));
+///
+/// assert_eq!(rest, (1, 1.0));
+/// assert_eq!(last, "test");
+/// ```
+pub const fn split_last<L, T>(tuple: T) -> (Left<T, { T::LEN - 1 }>, L)
+where
+    T: TupleLen + ~const TupleSplitAt<{ T::LEN - 1 }, Right = (L,)>
+{
+    let (rest, (last,)) = tuple.split_tuple_at();
+    (rest, last)
+}
+
+/// Reverses the whole tuple, and then splits it at a given index.
+///
+/// The left half holds the last `MIDDLE` elements of the original tuple, last one first. This is convenient for algorithms which consume a tuple from the tail.
+///
+/// Note that this is not the same as splitting first and then reversing each half. That keeps the left half at the front of the tuple, while here the halves come from
+/// the opposite ends.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = (1, 1.0, "test");
+///
+/// let (l, r) = tuple_split::reverse_split::<2, _>(t);
+///
+/// assert_eq!(l, ("test", 1.0));
+/// assert_eq!(r, (1,));
+/// ```
+pub const fn reverse_split<const MIDDLE: usize, T>(tuple: T) -> (Left<T::Reversed, MIDDLE>, Right<T::Reversed, MIDDLE>)
+where
+    T: ~const TupleReverse<Reversed: ~const TupleSplitAt<MIDDLE>>
+{
+    tuple.reverse_tuple().split_tuple_at()
+}
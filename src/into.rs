@@ -0,0 +1,224 @@
+//! Splitting a tuple into a given left part, right part, or both.
+//!
+//! The index isn't given, but follows from the types of the parts, like with [split_tuple_into_left](crate::into::split_tuple_into_left).
+//! With the feature `rejoin`, there's also [rejoin_checked](crate::into::rejoin_checked), which goes the other way.
+//!
+//! # Example
+//!
+//! ```rust
+//! #![feature(generic_const_exprs)]
+//!
+//! let t = (1, 1.0, "test");
+//!
+//! let (l, r) = tuple_split::into::split_tuple_into_left::<(u8, f32), _>(t);
+//! assert_eq!((l, r), ((1, 1.0), ("test",)));
+//! ```
+
+use core::marker::Tuple;
+
+use tupleops::TupleConcat;
+
+#[cfg(feature = "rejoin")]
+use crate::TupleRejoin;
+use crate::{Parts, Right, TupleElementwiseInto, TupleLen, TupleSplitAt, TupleSplitInto, TupleSplitIntoLeft, TupleSplitIntoRight, Whole};
+
+/// A trait for splitting a tuple up into two parts given a specified left part `L` and right part `R`. `L` and `R` must be the left and right part of `Self`.
+///
+/// Tuples will be split into parts `L` and `R`.
+///
+/// # Example
+///
+/// ```rust
+/// let t = (1, 1.0, "test");
+///
+/// let (l, r) = tuple_split::split_tuple_into::<(u8, f32), (&str,)>(t);
+///
+/// assert_eq!(t, tupleops::concat_tuples(l, r));
+/// ```
+pub const fn split_tuple_into<L, R>(tuple: Whole<L, R>) -> (L, R)
+where
+    L: Tuple,
+    R: Tuple,
+    (L, R): TupleConcat<L, R>,
+    Whole<L, R>: ~const TupleSplitInto<L, R>
+{
+    tuple.split_tuple_into()
+}
+
+/// Splits a tuple up into two parts given a specified left part `L`. `L` must be a leftmost segment of `Self`.
+///
+/// Tuples will be split into parts `L` and [TupleSplitIntoLeft::Right](TupleSplitIntoLeft::Right).
+///
+/// # Example
+///
+/// ```rust
+/// let t = (1, 1.0, "test");
+///
+/// let (l, r) = tuple_split::split_tuple_into_left::<(u8, f32), _>(t);
+///
+/// assert_eq!(t, tupleops::concat_tuples(l, r));
+/// ```
+pub const fn split_tuple_into_left<L, T>(tuple: T) -> (L, T::Right)
+where
+    L: Tuple,
+    T: ~const TupleSplitIntoLeft<L>
+{
+    tuple.split_tuple_into_left()
+}
+
+/// Splits tuple given a left part `L`, like [split_tuple_into_left](crate::split_tuple_into_left), but returns the parts as [Parts](crate::Parts).
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let parts = tuple_split::split_tuple_into_left_parts::<(u8, f32), _>((1, 1.0, "test"));
+///
+/// assert_eq!(parts.right, ("test",));
+/// ```
+pub const fn split_tuple_into_left_parts<L, T>(tuple: T) -> Parts<L, T::Right>
+where
+    L: Tuple,
+    T: ~const TupleSplitIntoLeft<L>
+{
+    let (left, right) = tuple.split_tuple_into_left();
+    Parts::from_parts(left, right)
+}
+
+/// Splits a tuple given its left part `L`, like [split_tuple_into_left](crate::split_tuple_into_left), but with the right part as a type parameter `R`.
+///
+/// For a concrete tuple, [TupleSplitIntoLeft::Right](TupleSplitIntoLeft::Right) is already the concrete right part, so with `R` left as `_`,
+/// this does the same as [split_tuple_into_left](crate::split_tuple_into_left). The difference is that `R` can also be written out to check it,
+/// like with [split_tuple_into](crate::split_tuple_into), but without needing the bounds that come with that.
+/// In generic code, the bound `T: TupleSplitIntoLeft<L, Right = R>` has to be carried along either way.
+///
+/// # Example
+///
+/// ```rust
+/// let t = (1, 1.0, "test");
+///
+/// let (l, r) = tuple_split::split_whole::<(u8, f32), _, _>(t);
+///
+/// assert_eq!(l, (1, 1.0));
+/// assert_eq!(r, ("test",));
+///
+/// let (l, r) = tuple_split::split_whole::<(u8,), (f32, &str), _>(t);
+///
+/// assert_eq!(l, (1,));
+/// assert_eq!(r, (1.0, "test"));
+/// ```
+///
+/// ```rust,compile_fail,E0271
+/// let (l, r) = tuple_split::split_whole::<(u8,), (&str, f32), _>((1, 1.0, "test"));
+/// ```
+pub const fn split_whole<L, R, T>(tuple: T) -> (L, R)
+where
+    L: Tuple,
+    R: Tuple,
+    T: ~const TupleSplitIntoLeft<L, Right = R>
+{
+    tuple.split_tuple_into_left()
+}
+
+/// Splits a tuple up into two parts given a specified right part `R`. `R` must be a rightmost segment of `Self`.
+///
+/// Tuples will be split into parts [TupleSplitIntoRight::Left](TupleSplitIntoRight::Left) and `R`.
+///
+/// # Example
+///
+/// ```rust
+/// let t = (1, 1.0, "test");
+///
+/// let (l, r) = tuple_split::split_tuple_into_right::<(&str,), _>(t);
+///
+/// assert_eq!(t, tupleops::concat_tuples(l, r));
+/// ```
+pub const fn split_tuple_into_right<R, T>(tuple: T) -> (T::Left, R)
+where
+    R: Tuple,
+    T: ~const TupleSplitIntoRight<R>
+{
+    tuple.split_tuple_into_right()
+}
+
+/// Splits tuple given a right part `R`, like [split_tuple_into_right](crate::split_tuple_into_right), but returns the parts as [Parts](crate::Parts).
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let parts = tuple_split::split_tuple_into_right_parts::<(&str,), _>((1, 1.0, "test"));
+///
+/// assert_eq!(parts.left, (1, 1.0));
+/// ```
+pub const fn split_tuple_into_right_parts<R, T>(tuple: T) -> Parts<T::Left, R>
+where
+    R: Tuple,
+    T: ~const TupleSplitIntoRight<R>
+{
+    let (left, right) = tuple.split_tuple_into_right();
+    Parts::from_parts(left, right)
+}
+
+/// Joins the halves `L` and `R` back together into the tuple `T`, checking at compile-time that they actually make up `T`.
+///
+/// Unlike [concat_tuples](tupleops::concat_tuples), the expected result is given up front, so if the halves don't make up `T`,
+/// the error points at the rejoin instead of wherever the resulting tuple is used. If the left half isn't the start of `T`, like when the halves are swapped,
+/// the error says that the halves can't be rejoined into `T`. If it is, the compiler works out the right half from `T` and `L`,
+/// so the error is a type mismatch on the right half instead.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = (1, 1.0, "test");
+///
+/// let (l, r) = tuple_split::split_tuple_at::<2, _>(t);
+///
+/// assert_eq!(tuple_split::rejoin_checked::<(u8, f32, &str), _, _>(l, r), t);
+/// ```
+///
+/// ```rust,compile_fail,E0277
+/// #![feature(generic_const_exprs)]
+///
+/// let (l, r) = tuple_split::split_tuple_at::<1, _>((1u8, 1.0f32, "test"));
+///
+/// let t = tuple_split::rejoin_checked::<(u8, f32, &str), _, _>(r, l);
+/// ```
+#[cfg(feature = "rejoin")]
+pub const fn rejoin_checked<T, L, R>(left: L, right: R) -> T
+where
+    T: ~const TupleRejoin<L, R>,
+    L: Tuple,
+    R: Tuple
+{
+    T::rejoin(left, right)
+}
+
+/// Splits a tuple up into two parts, where the left part is converted element-wise into `L` using [Into](core::convert::Into).
+///
+/// The left part has as many elements as `L`, but its element types may differ from `L`'s, as long as each can be converted into the corresponding element of `L`.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = (1u8, 2u8, "test");
+///
+/// let (l, r) = tuple_split::split_tuple_into_left_as::<(u32, u32), _>(t);
+///
+/// assert_eq!(l, (1u32, 2u32));
+/// assert_eq!(r, ("test",));
+/// ```
+pub fn split_tuple_into_left_as<L, T>(tuple: T) -> (L, Right<T, { L::LEN }>)
+where
+    L: TupleLen,
+    T: TupleSplitAt<{ L::LEN }, Left: TupleElementwiseInto<L>>
+{
+    let (left, right) = tuple.split_tuple_at();
+    (left.elementwise_into(), right)
+}
@@ -4,6 +4,8 @@
 #![feature(const_trait_impl)]
 #![feature(const_precise_live_drops)]
 #![feature(tuple_trait)]
+#![feature(adt_const_params)]
+#![feature(unsized_const_params)]
 #![recursion_limit = "512"]
 
 //!
@@ -98,50 +100,277 @@
 //! assert_eq!(t, tupleops::concat_tuples(l, r));
 //! ```
 //!
+//! ## Modules
+//!
+//! The functions are sorted into the modules [at], [into], [ends], [range] and [transform], by what they split and how.
+//! Everything in them is also re-exported at the crate root, where they used to be, so paths like `tuple_split::split_tuple_at` keep working.
+//!
 //! ## Tuple sizes
 //!
 //! By default, this crate operates with tuples of up to 16 elements, just like the [tupleops](https://crates.io/crates/tupleops) crate.
 //! If you want to use differently sized tuples, use the features `8`, `16`, `32`, `64`, `96`, `128`, `160`, `192`, `224` or `256` to set the maximum supported tuple
-//! size.
+//! size. The size that ends up supported is available as [MAX_TUPLE_SIZE].
 //!
 //! The `dont_hurt_yourself_by_using_all_features` is there to prevent usage of tuples bigger than 8 if `cargo` is ran with the flag `--all-features`.
 //! Using a tuple size above 16 is highly discouraged as it will make compilation time unbearably long. Compilation time will increase exponentially.
 //! You have been warned.
+//!
+//! Sizes in between the features, or past 256, can't be added from another crate. The orphan rule forbids implementing this crate's traits for tuples in any
+//! crate but this one, since neither the traits nor the tuples would be local there. A new size has to be added here, as another feature.
+//!
+//! ## Futures
+//!
+//! The feature `futures` enables splitting pinned tuples, like tuples of futures, into pinned halves. See [split_pinned_futures_at](crate::split_pinned_futures_at).
+//!
+//! ## Alloc
+//!
+//! The feature `alloc` enables splitting tuples at an index only known at runtime, into boxed halves with their element types erased.
+//! See [split_erased_at](crate::split_erased_at).
+//!
+//! ## Serde
+//!
+//! The feature `serde` implements `Serialize` and `Deserialize` for [Parts](crate::Parts), as a pair of its two halves, so that each half is (de)serialized
+//! as its own section. Split a tuple with [split_tuple_at_parts](crate::split_tuple_at_parts) to get one.
+//!
+//! ## Arrays
+//!
+//! The feature `array_split` lets [split_tuple_at](crate::split_tuple_at) split arrays as well as tuples, into two arrays. See [Splittable](crate::Splittable).
+//!
+//! ## References, nested tuples and rejoining
+//!
+//! The features `refs`, `nested` and `rejoin` each implement another trait for every way to split every supported tuple size, so they add noticeably to
+//! compilation time, and are off by default.
+//!
+//! - `refs` enables splitting borrowed tuples into tuples of references, with [TupleSplitAtRef](crate::TupleSplitAtRef). See
+//!   [split_tuple_at_ref](crate::split_tuple_at_ref).
+//! - `nested` enables splitting nested tuples at a flat index, with [NestedSplit](crate::NestedSplit). See [split_nested_at](crate::split_nested_at).
+//! - `rejoin` enables joining split halves back together with a type check, with [TupleRejoin](crate::TupleRejoin). See [rejoin_checked](crate::rejoin_checked).
 
-use core::marker::Tuple;
+use core::{any::Any, marker::Tuple};
+#[cfg(feature = "futures")]
+use core::{future::Future, pin::Pin};
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 use blk_count_macro::count;
 use tupleops::{ConcatTuples, TupleConcat};
 
+pub use self::{at::*, ends::*, into::*, range::*, transform::*};
+
+pub mod at;
+pub mod ends;
+pub mod into;
+pub mod range;
+pub mod transform;
+
 /// Type alias [Left](Left) equals [TupleSplit::Left](TupleSplit::Left)
 /// for any tuple which implements [TupleSplit](crate::TupleSplit) at the given MIDDLE.
-pub type Left<T, const MIDDLE: usize> = <T as TupleSplitAt<MIDDLE>>::Left;
+///
+/// Both halves always have the trait [TupleLen](crate::TupleLen), so their lengths can be read off the aliases without any extra bounds.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// use tuple_split::{Left, Right, TupleLen, TupleSplitAt};
+///
+/// fn half_lens<T, const MIDDLE: usize>(_: &T) -> (usize, usize)
+/// where
+///     T: TupleSplitAt<MIDDLE>
+/// {
+///     (<Left<T, MIDDLE>>::LEN, <Right<T, MIDDLE>>::LEN)
+/// }
+///
+/// assert_eq!(half_lens::<_, 2>(&(1, 1.0, "test")), (2, 1));
+/// ```
+pub type Left<T, const MIDDLE: usize> = <T as SplitTypeAt<MIDDLE>>::Left;
 /// Type alias [Right](Right) equals [TupleSplit::Right](TupleSplit::Right)
 /// for any tuple which implements [TupleSplit](crate::TupleSplit) at the given MIDDLE.
-pub type Right<T, const MIDDLE: usize> = <T as TupleSplitAt<MIDDLE>>::Right;
-
-/// Tuples which may be split at index `MIDDLE` have the trait [TupleSplitAt](crate::TupleSplitAt),
-/// which, when split, returns [TupleSplitAt::Left](TupleSplitAt::Left), [TupleSplitAt::Right](TupleSplitAt::Right).
+pub type Right<T, const MIDDLE: usize> = <T as SplitTypeAt<MIDDLE>>::Right;
+/// Type alias [RightOf](RightOf) equals [TupleSplitIntoLeft::Right](TupleSplitIntoLeft::Right)
+/// for any tuple which implements [TupleSplitIntoLeft](crate::TupleSplitIntoLeft) with the given left part `L`.
 ///
 /// # Example
 ///
 /// ```rust
-/// #![feature(generic_const_exprs)]
+/// use core::num::Wrapping;
 ///
-/// let t = (1, 1.0, "test");
+/// fn rest<T>(t: T) -> tuple_split::RightOf<T, (Wrapping<u8>,)>
+/// where
+///     T: tuple_split::TupleSplitIntoLeft<(Wrapping<u8>,)>
+/// {
+///     tuple_split::split_tuple_into_left(t).1
+/// }
 ///
-/// let (l, r) = tuple_split::split_tuple_at::<2, _>(t);
+/// assert_eq!(rest((Wrapping(1u8), Wrapping(2u16))), (Wrapping(2u16),));
+/// ```
+pub type RightOf<T, L> = <T as TupleSplitIntoLeft<L>>::Right;
+/// Type alias [LeftOf](LeftOf) equals [TupleSplitIntoRight::Left](TupleSplitIntoRight::Left)
+/// for any tuple which implements [TupleSplitIntoRight](crate::TupleSplitIntoRight) with the given right part `R`.
+pub type LeftOf<T, R> = <T as TupleSplitIntoRight<R>>::Left;
+/// Type alias [Whole](Whole) equals [ConcatTuples](tupleops::ConcatTuples), the tuple which is split up into `L` and `R` by [split_tuple_into](crate::split_tuple_into).
 ///
-/// assert_eq!(t, tupleops::concat_tuples(l, r));
+/// Like [ConcatTuples](tupleops::ConcatTuples), it's only defined when `(L, R)` implements [TupleConcat](tupleops::TupleConcat).
+///
+/// # Example
+///
+/// ```rust
+/// fn halves(t: tuple_split::Whole<(u8,), (f32,)>) -> ((u8,), (f32,))
+/// {
+///     tuple_split::split_tuple_into(t)
+/// }
+///
+/// assert_eq!(halves((1, 1.0)), ((1,), (1.0,)));
 /// ```
-#[diagnostic::on_unimplemented(message = "`{Self}` cannot be split at index `{MIDDLE}`")]
-#[const_trait]
-pub trait TupleSplitAt<const MIDDLE: usize>: Tuple
+pub type Whole<L, R> = ConcatTuples<L, R>;
+
+/// Type alias [Padding](Padding) is a homogeneous tuple of `N` elements of type `E`, as made by [ArrayToTuple](crate::ArrayToTuple).
+pub type Padding<E, const N: usize> = <[E; N] as ArrayToTuple>::Tupled;
+/// Type alias [LeftRefs](LeftRefs) is a tuple of references to the elements of [Left](Left).
+pub type LeftRefs<'a, T, const MIDDLE: usize> = <Left<T, MIDDLE> as TupleRefs>::Refs<'a>;
+/// Type alias [RightRefs](RightRefs) is a tuple of references to the elements of [Right](Right).
+pub type RightRefs<'a, T, const MIDDLE: usize> = <Right<T, MIDDLE> as TupleRefs>::Refs<'a>;
+/// Type alias [LeftMuts](LeftMuts) is a tuple of mutable references to the elements of [Left](Left).
+pub type LeftMuts<'a, T, const MIDDLE: usize> = <Left<T, MIDDLE> as TupleRefs>::Muts<'a>;
+/// Type alias [RightMuts](RightMuts) is a tuple of mutable references to the elements of [Right](Right).
+pub type RightMuts<'a, T, const MIDDLE: usize> = <Right<T, MIDDLE> as TupleRefs>::Muts<'a>;
+
+/// Adds the diagnostic given when a value can't be split at index `MIDDLE` to a trait, with any extra notes.
+macro_rules! split_at_diagnostic {
+    (notes = [$($note:literal),*]; $item:item) => {
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` cannot be split at index `{MIDDLE}`",
+            label = "cannot be split at index `{MIDDLE}`",
+            note = "`{Self}` can only be split at indices `0..=LEN`, where `LEN` is its number of elements",
+            note = "tuples of up to `tuple_split::MAX_TUPLE_SIZE` elements are supported, which depends on the enabled size features"
+            $(, note = $note)*
+        )]
+        $item
+    };
+    ($item:item) => {
+        split_at_diagnostic!{notes = []; $item}
+    };
+}
+
+split_at_diagnostic! {
+    /// Tuples which may be split at index `MIDDLE` have the trait [TupleSplitAt](crate::TupleSplitAt),
+    /// which, when split, returns [SplitTypeAt::Left](SplitTypeAt::Left), [SplitTypeAt::Right](SplitTypeAt::Right).
+    ///
+    /// Since version 0.3, the halves are declared on [SplitTypeAt](crate::SplitTypeAt), which this trait requires. `T::Left` and bounds like
+    /// `TupleSplitAt<MIDDLE, Left = L>` work as before, but fully qualified paths have to name [SplitTypeAt](crate::SplitTypeAt), like
+    /// `<T as SplitTypeAt<MIDDLE>>::Left`, or use the alias [Left](crate::Left).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    ///
+    /// let t = (1, 1.0, "test");
+    ///
+    /// let (l, r) = tuple_split::split_tuple_at::<2, _>(t);
+    ///
+    /// assert_eq!(t, tupleops::concat_tuples(l, r));
+    /// ```
+    ///
+    /// A tuple of length `LEN` can be split at any index in `0..=LEN`. Splitting it anywhere else fails to compile, with a note saying so.
+    ///
+    /// ```rust,compile_fail,E0277
+    /// #![feature(generic_const_exprs)]
+    ///
+    /// let (l, r) = tuple_split::split_tuple_at::<4, _>((1, 1.0, "test"));
+    /// ```
+    #[const_trait]
+    pub trait TupleSplitAt<const MIDDLE: usize>: SplitTypeAt<MIDDLE>
+    {
+        fn split_tuple_at(self) -> (Self::Left, Self::Right);
+    }
+}
+
+split_at_diagnostic! {
+    notes = ["only tuples, and arrays with the feature `array_split`, can be split"];
+
+    /// Values which may be split at index `MIDDLE` by [split_tuple_at](crate::split_tuple_at) have the trait [Splittable](crate::Splittable).
+    ///
+    /// Every tuple which has the trait [TupleSplitAt](crate::TupleSplitAt) has it. With the feature `array_split`, so do arrays, which are split into two arrays.
+    /// The trait is sealed, so it can't be implemented for any other types.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    ///
+    /// use tuple_split::Splittable;
+    ///
+    /// let (l, r) = Splittable::<2>::split((1, 1.0, "test"));
+    ///
+    /// assert_eq!(l, (1, 1.0));
+    /// assert_eq!(r, ("test",));
+    /// ```
+    #[const_trait]
+    pub trait Splittable<const MIDDLE: usize>: private::SealedSplittable<MIDDLE>
+    {
+        type Left;
+        type Right;
+
+        fn split(self) -> (Self::Left, Self::Right);
+    }
+}
+
+impl<T, const MIDDLE: usize> const Splittable<MIDDLE> for T
+where
+    T: ~const TupleSplitAt<MIDDLE>
 {
-    type Left: Tuple;
-    type Right: Tuple;
+    type Left = T::Left;
+    type Right = T::Right;
+
+    fn split(self) -> (Self::Left, Self::Right)
+    {
+        self.split_tuple_at()
+    }
+}
+
+#[cfg(feature = "array_split")]
+impl<E, const N: usize, const MIDDLE: usize> const Splittable<MIDDLE> for [E; N]
+where
+    [(); N - MIDDLE]:
+{
+    type Left = [E; MIDDLE];
+    type Right = [E; N - MIDDLE];
+
+    fn split(self) -> (Self::Left, Self::Right)
+    {
+        let array = core::mem::ManuallyDrop::new(self);
+        let ptr = (&raw const array).cast::<E>();
+        // SAFETY: `MIDDLE <= N`, since `N - MIDDLE` would otherwise fail to evaluate. The halves cover the `N` elements exactly once,
+        // and the array is never dropped, so each element is moved out exactly once.
+        unsafe { (ptr.cast::<[E; MIDDLE]>().read(), ptr.add(MIDDLE).cast::<[E; N - MIDDLE]>().read()) }
+    }
+}
 
-    fn split_tuple_at(self) -> (Self::Left, Self::Right);
+split_at_diagnostic! {
+    /// Tuple types which may be split at index `MIDDLE` have the trait [SplitTypeAt](crate::SplitTypeAt), which only names the types of the two halves,
+    /// [SplitTypeAt::Left](SplitTypeAt::Left) and [SplitTypeAt::Right](SplitTypeAt::Right).
+    ///
+    /// This lets generic code work out the halves of a tuple type without having a value of it. [TupleSplitAt](crate::TupleSplitAt) requires it, and splits values
+    /// into these halves.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    ///
+    /// use tuple_split::SplitTypeAt;
+    ///
+    /// type L = <(u8, f32, &'static str) as SplitTypeAt<2>>::Left;
+    ///
+    /// let l: L = (1, 1.0);
+    /// ```
+    pub trait SplitTypeAt<const MIDDLE: usize>: Tuple
+    {
+        type Left: TupleLen;
+        type Right: TupleLen;
+    }
 }
 
 /// A trait for splitting a tuple up into two parts given a specified left part `L` and right part `R`. `L` and `R` must be the left and right part of `Self`.
@@ -173,6 +402,7 @@ where
     L: Tuple,
     R: Tuple
 {
+    #[inline(always)]
     fn split_tuple_into(self) -> (L, R)
     {
         self.split_tuple_into_left()
@@ -227,356 +457,3711 @@ where
     fn split_tuple_into_right(self) -> (Self::Left, R);
 }
 
-/// Splits tuple at a given index.
+/// Tuples have the trait [TupleLen](crate::TupleLen), which gives their number of elements as [TupleLen::LEN](TupleLen::LEN).
+///
+/// # Example
+///
+/// ```rust
+/// use tuple_split::TupleLen;
 ///
-/// Index is specified as const generic `MIDDLE.
+/// assert_eq!(<(u8, f32, &str)>::LEN, 3);
+/// assert_eq!(<()>::LEN, 0);
+/// ```
+pub trait TupleLen: Tuple
+{
+    const LEN: usize;
+}
+
+/// A type-level integer. Types with the trait [ConstUsize](crate::ConstUsize) carry the number [ConstUsize::VALUE](ConstUsize::VALUE).
 ///
-/// Tuple must be of trait `[TupleSplitAt](crate::TupleSplitAt)<MIDDLE>`.
+/// This allows a split index to be passed around as a type parameter, for example when it's computed by other type-level machinery.
+/// See [split_tuple_at_const](crate::split_tuple_at_const).
 ///
-/// Returns `([TupleSplitAt::Left](TupleSplitAt::Left), [TupleSplitAt::Right](TupleSplitAt::Right))` for the given Tuple and `MIDDLE`.
+/// # Example
 ///
 /// ```rust
-/// #![feature(generic_const_exprs)]
+/// use tuple_split::ConstUsize;
 ///
-/// let t = (1, 1.0, "test");
+/// struct Two;
 ///
-/// let (l, r) = tuple_split::split_tuple_at::<2, _>(t);
+/// impl ConstUsize for Two
+/// {
+///     const VALUE: usize = 2;
+/// }
 ///
-/// assert_eq!(t, tupleops::concat_tuples(l, r));
+/// assert_eq!(Two::VALUE, 2);
 /// ```
-pub const fn split_tuple_at<const MIDDLE: usize, T>(tuple: T) -> (T::Left, T::Right)
-where
-    T: ~const TupleSplitAt<MIDDLE>
+pub trait ConstUsize
 {
-    tuple.split_tuple_at()
+    const VALUE: usize;
 }
 
-/// A trait for splitting a tuple up into two parts given a specified left part `L` and right part `R`. `L` and `R` must be the left and right part of `Self`.
-///
-/// Tuples will be split into parts `L` and `R`.
+/// A visitor which transforms the two halves `L` and `R` of a split tuple into new tuples. Used by [split_map_rebuild](crate::split_map_rebuild).
 ///
 /// # Example
 ///
 /// ```rust
-/// let t = (1, 1.0, "test");
+/// use tuple_split::SplitVisitor;
 ///
-/// let (l, r) = tuple_split::split_tuple_into::<(u8, f32), (&str,)>(t);
+/// struct Double;
 ///
-/// assert_eq!(t, tupleops::concat_tuples(l, r));
+/// impl SplitVisitor<(u8,), (u16,)> for Double
+/// {
+///     type Left = (u8,);
+///     type Right = (u16,);
+///
+///     fn transform_left(&mut self, (a,): (u8,)) -> Self::Left
+///     {
+///         (a * 2,)
+///     }
+///
+///     fn transform_right(&mut self, (b,): (u16,)) -> Self::Right
+///     {
+///         (b * 2,)
+///     }
+/// }
+///
+/// assert_eq!(Double.transform_left((1,)), (2,));
+/// assert_eq!(Double.transform_right((2,)), (4,));
 /// ```
-pub const fn split_tuple_into<L, R>(tuple: ConcatTuples<L, R>) -> (L, R)
+pub trait SplitVisitor<L, R>
+where
+    L: Tuple,
+    R: Tuple
+{
+    type Left: Tuple;
+    type Right: Tuple;
+
+    fn transform_left(&mut self, left: L) -> Self::Left;
+    fn transform_right(&mut self, right: R) -> Self::Right;
+}
+
+impl<L, R, V> SplitVisitor<L, R> for &mut V
 where
     L: Tuple,
     R: Tuple,
-    (L, R): TupleConcat<L, R>,
-    ConcatTuples<L, R>: ~const TupleSplitInto<L, R>
+    V: SplitVisitor<L, R> + ?Sized
 {
-    tuple.split_tuple_into()
+    type Left = V::Left;
+    type Right = V::Right;
+
+    fn transform_left(&mut self, left: L) -> Self::Left
+    {
+        (**self).transform_left(left)
+    }
+
+    fn transform_right(&mut self, right: R) -> Self::Right
+    {
+        (**self).transform_right(right)
+    }
 }
 
-/// Splits a tuple up into two parts given a specified left part `L`. `L` must be a leftmost segment of `Self`.
-///
-/// Tuples will be split into parts `L` and [TupleSplitIntoLeft::Right](TupleSplitIntoLeft::Right).
+/// Tuples have the trait [TupleChunks](crate::TupleChunks), which splits them into a tuple of consecutive chunks of `N` elements each.
+/// If the length of the tuple isn't divisible by `N`, the last chunk is shorter, just like with [slice::chunks](slice::chunks).
 ///
 /// # Example
 ///
 /// ```rust
-/// let t = (1, 1.0, "test");
+/// #![feature(generic_const_exprs)]
 ///
-/// let (l, r) = tuple_split::split_tuple_into_left::<(u8, f32), _>(t);
+/// use tuple_split::TupleChunks;
 ///
-/// assert_eq!(t, tupleops::concat_tuples(l, r));
+/// let c = TupleChunks::<3>::chunks_tuple((1, 2, 3, 4));
+///
+/// assert_eq!(c, ((1, 2, 3), (4,)));
 /// ```
-pub const fn split_tuple_into_left<L, T>(tuple: T) -> (L, T::Right)
+#[diagnostic::on_unimplemented(message = "`{Self}` cannot be split into chunks of `{N}` elements")]
+pub trait TupleChunks<const N: usize>: Tuple
+{
+    type Chunks: Tuple;
+
+    fn chunks_tuple(self) -> Self::Chunks;
+}
+
+impl<T, const N: usize> TupleChunks<N> for T
 where
-    L: Tuple,
-    T: ~const TupleSplitIntoLeft<L>
+    T: TupleLen + private::TupleChunksCase<N, { private::chunks_case(T::LEN, N) }>
 {
-    tuple.split_tuple_into_left()
+    type Chunks = T::Chunks;
+
+    fn chunks_tuple(self) -> Self::Chunks
+    {
+        self.chunks_case()
+    }
 }
 
-/// Splits a tuple up into two parts given a specified right part `R`. `R` must be a rightmost segment of `Self`.
-///
-/// Tuples will be split into parts [TupleSplitIntoRight::Left](TupleSplitIntoRight::Left) and `R`.
+/// Tuples which have an element at index `INDEX` have the trait [TupleRemoveAt](crate::TupleRemoveAt), which removes that element,
+/// returning the remaining elements and the removed one.
 ///
 /// # Example
 ///
 /// ```rust
-/// let t = (1, 1.0, "test");
+/// #![feature(generic_const_exprs)]
 ///
-/// let (l, r) = tuple_split::split_tuple_into_right::<(&str,), _>(t);
+/// use tuple_split::TupleRemoveAt;
 ///
-/// assert_eq!(t, tupleops::concat_tuples(l, r));
+/// let (rest, removed) = TupleRemoveAt::<1>::remove_at((1, 1.0, "test"));
+///
+/// assert_eq!(rest, (1, "test"));
+/// assert_eq!(removed, 1.0);
 /// ```
-pub const fn split_tuple_into_right<R, T>(tuple: T) -> (T::Left, R)
-where
-    R: Tuple,
-    T: ~const TupleSplitIntoRight<R>
+#[diagnostic::on_unimplemented(message = "`{Self}` has no element at index `{INDEX}` to remove")]
+pub trait TupleRemoveAt<const INDEX: usize>: Tuple
 {
-    tuple.split_tuple_into_right()
-}
+    type Remaining: Tuple;
+    type Removed;
 
-macro_rules! impl_split_single {
-    (( $($types1:ident),* ), ( $($types2:ident),* )) => {
-        impl<$($types1,)* $($types2,)*> const TupleSplitAt<{count!($($types1),*)}> for ($($types1,)* $($types2,)*)
-        {
-            type Left = ($($types1,)*);
-            type Right = ($($types2,)*);
+    fn remove_at(self) -> (Self::Remaining, Self::Removed);
+}
 
-            fn split_tuple_at(self) -> (Self::Left, Self::Right)
-            {
-                let ($($types1,)* $($types2,)*) = self;
-                (($($types1,)*), ($($types2,)*))
-            }
-        }
+impl<T, E, R, const INDEX: usize> TupleRemoveAt<INDEX> for T
+where
+    T: TupleSplitAt<INDEX, Right: TupleSplitAt<1, Left = (E,), Right = R>>,
+    (T::Left, R): TupleConcat<T::Left, R, Type: Tuple>
+{
+    type Remaining = ConcatTuples<T::Left, R>;
+    type Removed = E;
 
-        impl<$($types1,)* $($types2,)*> const TupleSplitIntoLeft<($($types1,)*)> for ($($types1,)* $($types2,)*)
-        {
-            type Right = ($($types2,)*);
+    fn remove_at(self) -> (Self::Remaining, Self::Removed)
+    {
+        let (left, right) = self.split_tuple_at();
+        let ((removed,), right) = right.split_tuple_at();
+        (tupleops::concat_tuples(left, right), removed)
+    }
+}
 
-            fn split_tuple_into_left(self) -> (($($types1,)*), ($($types2,)*))
-            {
-                let ($($types1,)* $($types2,)*) = self;
-                (($($types1,)*), ($($types2,)*))
-            }
-        }
-        impl<$($types1,)* $($types2,)*> const TupleSplitIntoRight<($($types2,)*)> for ($($types1,)* $($types2,)*)
-        {
-            type Left = ($($types1,)*);
+/// Tuples which have an element at each of the indices in `INDICES` have the trait [TupleRemoveMany](crate::TupleRemoveMany), which removes those elements,
+/// returning the remaining elements and a tuple of the removed ones, in order.
+///
+/// The indices are relative to the original tuple, so there's no need to account for each removal shifting the elements after it.
+/// They must be strictly ascending.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// use tuple_split::TupleRemoveMany;
+///
+/// let (rest, removed) = TupleRemoveMany::<{ &[0, 2] }>::remove_many((1, 1.0, "test", 'c'));
+///
+/// assert_eq!(rest, (1.0, 'c'));
+/// assert_eq!(removed, (1, "test"));
+/// ```
+#[diagnostic::on_unimplemented(message = "`{Self}` doesn't have elements at all of the indices `{INDICES}` to remove")]
+pub trait TupleRemoveMany<const INDICES: &'static [usize]>: Tuple
+{
+    type Remaining: Tuple;
+    type Removed: Tuple;
 
-            fn split_tuple_into_right(self) -> (($($types1,)*), ($($types2,)*))
-            {
-                let ($($types1,)* $($types2,)*) = self;
-                (($($types1,)*), ($($types2,)*))
-            }
-        }
-    };
+    fn remove_many(self) -> (Self::Remaining, Self::Removed);
 }
-macro_rules! impl_split_combinations {
-    ( (), ( $($types2:ident),* ) ) => {
-        impl_split_single!{(), ($($types2),*)}
-    };
-    (($t0:ident $(,$types1:ident)* ), ( $($types2:ident),* )) => {
-        impl_split_single!{($t0 $(,$types1)*), ($($types2),*)}
 
-        impl_split_combinations!{($($types1),*), ($t0 $(,$types2)*)}
-    };
-    (($($types:ident),*)) => {
-        impl_split_combinations!{($($types),*), ()}
-    }
-}
-macro_rules! impl_split_all {
-    (()) => {
-        impl_split_combinations!{()}
-    };
-    (($t0:ident $(,$types:ident)*)) => {
-        impl_split_combinations!{($t0 $(,$types)*)}
+impl<T, const INDICES: &'static [usize]> TupleRemoveMany<INDICES> for T
+where
+    T: private::RemoveFirst<INDICES, { INDICES.len() }>
+{
+    type Remaining = T::Remaining;
+    type Removed = T::Removed;
 
-        impl_split_all!{($($types),*)}
+    fn remove_many(self) -> (Self::Remaining, Self::Removed)
+    {
+        self.remove_first()
     }
 }
 
-#[cfg(not(feature = "8"))]
-impl_split_all! {
-    (
-        _1, _2, _3, _4
-    )
+/// Tuples have the trait [TupleShape](crate::TupleShape), which describes the layout of their elements.
+/// [TupleShape::SHAPE](TupleShape::SHAPE) has the size and alignment of each element, in order, and its length is the number of elements.
+///
+/// The layout of the tuple itself isn't described, since the compiler is free to reorder and pad its elements.
+///
+/// # Example
+///
+/// ```rust
+/// use tuple_split::TupleShape;
+///
+/// assert_eq!(<(u8, u32)>::SHAPE, &[(1, 1), (4, 4)]);
+/// ```
+pub trait TupleShape: Tuple
+{
+    const SHAPE: &'static [(usize, usize)];
 }
 
-#[cfg(feature = "8")]
-#[cfg(any(feature = "dont_hurt_yourself_by_using_all_features", not(feature = "16")))]
-impl_split_all! {
-    (
-        _1, _2, _3, _4, _5, _6, _7, _8
-    )
+/// Tuples of tuples have the trait [TupleNested](crate::TupleNested). They can represent a logical tuple which is the concatenation of the inner tuples,
+/// called chunks, which may be longer than the maximum supported tuple size.
+///
+/// [TupleNested::CHUNK_LENS](TupleNested::CHUNK_LENS) are the lengths of the chunks, and [TupleNested::FLAT_LEN](TupleNested::FLAT_LEN) is the length of the
+/// logical tuple.
+///
+/// # Example
+///
+/// ```rust
+/// use tuple_split::TupleNested;
+///
+/// type Nested = ((u8, u8), (), (u8, u8, u8));
+///
+/// assert_eq!(Nested::CHUNK_LENS, &[2, 0, 3]);
+/// assert_eq!(Nested::FLAT_LEN, 5);
+/// ```
+pub trait TupleNested: Tuple
+{
+    const CHUNK_LENS: &'static [usize];
+    const FLAT_LEN: usize;
 }
 
-#[cfg(feature = "16")]
-#[cfg(not(feature = "dont_hurt_yourself_by_using_all_features"))]
-#[cfg(not(feature = "32"))]
-impl_split_all! {
-    (
-        _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16
-    )
+/// Nested tuples which may be split at the flat index `INDEX` of the logical tuple they represent have the trait [NestedSplit](crate::NestedSplit).
+/// See [TupleNested](crate::TupleNested).
+///
+/// The chunk containing the split point is itself split in two, so the halves are nested tuples too. If `INDEX` is at the boundary between two chunks,
+/// the earlier chunk is split at its end, so the right half starts with an empty chunk.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = ((1, 2), (3, 4, 5));
+///
+/// let (l, r) = tuple_split::split_nested_at::<3, _>(t);
+///
+/// assert_eq!(l, ((1, 2), (3,)));
+/// assert_eq!(r, ((4, 5),));
+/// ```
+#[cfg(feature = "nested")]
+#[diagnostic::on_unimplemented(message = "nested tuple `{Self}` cannot be split at flat index `{INDEX}`")]
+pub trait NestedSplit<const INDEX: usize>: TupleNested
+{
+    type Left: TupleNested;
+    type Right: TupleNested;
+
+    fn split_nested(self) -> (Self::Left, Self::Right);
 }
 
-#[cfg(feature = "32")]
-#[cfg(not(feature = "dont_hurt_yourself_by_using_all_features"))]
-#[cfg(not(feature = "64"))]
-impl_split_all! {
-    (
-        _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16,
-        _17, _18, _19, _20, _21, _22, _23, _24, _25, _26, _27, _28, _29, _30, _31, _32
-    )
+#[cfg(feature = "nested")]
+impl<T, const INDEX: usize> NestedSplit<INDEX> for T
+where
+    T: TupleNested + private::NestedSplitAt<{ private::nested_chunk(T::CHUNK_LENS, INDEX) }, { private::nested_offset(T::CHUNK_LENS, INDEX) }>
+{
+    type Left = T::Left;
+    type Right = T::Right;
+
+    fn split_nested(self) -> (Self::Left, Self::Right)
+    {
+        self.split_nested_at()
+    }
 }
 
-#[cfg(feature = "64")]
-#[cfg(not(feature = "dont_hurt_yourself_by_using_all_features"))]
-#[cfg(not(feature = "96"))]
-impl_split_all! {
-    (
-        _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16,
-        _17, _18, _19, _20, _21, _22, _23, _24, _25, _26, _27, _28, _29, _30, _31, _32,
-        _33, _34, _35, _36, _37, _38, _39, _40, _41, _42, _43, _44, _45, _46, _47, _48,
-        _49, _50, _51, _52, _53, _54, _55, _56, _57, _58, _59, _60, _61, _62, _63, _64
-    )
+/// Tuples whose elements are all `'static` have the trait [TupleOptions](crate::TupleOptions), which describes a store of their elements as
+/// a tuple of [Option](core::option::Option)s, where each element may be filled in individually at runtime. Used by [TupleAccumulator](crate::TupleAccumulator).
+pub trait TupleOptions: TupleLen + Sized
+{
+    type Options: Tuple;
+
+    /// Returns a store where no element is filled in.
+    fn empty_options() -> Self::Options;
+    /// Returns the slot for the element at `index` as [Any](core::any::Any), or `None` if the index is out of range.
+    fn option_slot(options: &mut Self::Options, index: usize) -> Option<&mut dyn Any>;
+    /// Returns the tuple if every element has been filled in, otherwise gives back the store.
+    fn try_from_options(options: Self::Options) -> Result<Self, Self::Options>;
 }
 
-#[cfg(feature = "96")]
-#[cfg(not(feature = "dont_hurt_yourself_by_using_all_features"))]
-#[cfg(not(feature = "128"))]
-impl_split_all! {
-    (
-        _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16,
-        _17, _18, _19, _20, _21, _22, _23, _24, _25, _26, _27, _28, _29, _30, _31, _32,
-        _33, _34, _35, _36, _37, _38, _39, _40, _41, _42, _43, _44, _45, _46, _47, _48,
-        _49, _50, _51, _52, _53, _54, _55, _56, _57, _58, _59, _60, _61, _62, _63, _64,
-        _65, _66, _67, _68, _69, _70, _71, _72, _73, _74, _75, _76, _77, _78, _79, _80,
-        _81, _82, _83, _84, _85, _86, _87, _88, _89, _90, _91, _92, _93, _94, _95, _96
-    )
+/// Tuples have the trait [TupleReverse](crate::TupleReverse), which reverses the order of their elements.
+///
+/// # Example
+///
+/// ```rust
+/// use tuple_split::TupleReverse;
+///
+/// let t = (1, 1.0, "test").reverse_tuple();
+///
+/// assert_eq!(t, ("test", 1.0, 1));
+/// ```
+#[const_trait]
+pub trait TupleReverse: Tuple
+{
+    type Reversed: Tuple;
+
+    fn reverse_tuple(self) -> Self::Reversed;
 }
 
-#[cfg(feature = "128")]
-#[cfg(not(feature = "dont_hurt_yourself_by_using_all_features"))]
-#[cfg(not(feature = "160"))]
-impl_split_all! {
-    (
-        _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16,
-        _17, _18, _19, _20, _21, _22, _23, _24, _25, _26, _27, _28, _29, _30, _31, _32,
-        _33, _34, _35, _36, _37, _38, _39, _40, _41, _42, _43, _44, _45, _46, _47, _48,
-        _49, _50, _51, _52, _53, _54, _55, _56, _57, _58, _59, _60, _61, _62, _63, _64,
-        _65, _66, _67, _68, _69, _70, _71, _72, _73, _74, _75, _76, _77, _78, _79, _80,
-        _81, _82, _83, _84, _85, _86, _87, _88, _89, _90, _91, _92, _93, _94, _95, _96,
-        _97, _98, _99, _100, _101, _102, _103, _104, _105, _106, _107, _108, _109, _110, _111, _112,
-        _113, _114, _115, _116, _117, _118, _119, _120, _121, _122, _123, _124, _125, _126, _127, _128
-    )
+/// Tuples have the trait [TupleUnwrapSingle](crate::TupleUnwrapSingle), which turns a 1-tuple `(A,)` into its bare element `A`.
+/// Tuples of any other length are left as they are.
+///
+/// # Example
+///
+/// ```rust
+/// use tuple_split::TupleUnwrapSingle;
+///
+/// assert_eq!((1,).unwrap_single(), 1);
+/// assert_eq!((1, 1.0).unwrap_single(), (1, 1.0));
+/// ```
+#[const_trait]
+pub trait TupleUnwrapSingle: Tuple
+{
+    type Unwrapped;
+
+    fn unwrap_single(self) -> Self::Unwrapped;
 }
 
-#[cfg(feature = "160")]
-#[cfg(not(feature = "dont_hurt_yourself_by_using_all_features"))]
-#[cfg(not(feature = "192"))]
-impl_split_all! {
-    (
-        _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16,
-        _17, _18, _19, _20, _21, _22, _23, _24, _25, _26, _27, _28, _29, _30, _31, _32,
-        _33, _34, _35, _36, _37, _38, _39, _40, _41, _42, _43, _44, _45, _46, _47, _48,
-        _49, _50, _51, _52, _53, _54, _55, _56, _57, _58, _59, _60, _61, _62, _63, _64,
-        _65, _66, _67, _68, _69, _70, _71, _72, _73, _74, _75, _76, _77, _78, _79, _80,
-        _81, _82, _83, _84, _85, _86, _87, _88, _89, _90, _91, _92, _93, _94, _95, _96,
-        _97, _98, _99, _100, _101, _102, _103, _104, _105, _106, _107, _108, _109, _110, _111, _112,
-        _113, _114, _115, _116, _117, _118, _119, _120, _121, _122, _123, _124, _125, _126, _127, _128,
-        _129, _130, _131, _132, _133, _134, _135, _136, _137, _138, _139, _140, _141, _142, _143, _144,
-        _145, _146, _147, _148, _149, _150, _151, _152, _153, _154, _155, _156, _157, _158, _159, _160
-    )
+/// A trait for converting each element of a tuple into the corresponding element of the tuple `T`, using [Into](core::convert::Into).
+///
+/// Since this is implemented using [TupleSplitAt](crate::TupleSplitAt), it requires `#![feature(generic_const_exprs)]`.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// use tuple_split::TupleElementwiseInto;
+///
+/// let t: (u32, f64) = (1u8, 1.0f32).elementwise_into();
+///
+/// assert_eq!(t, (1, 1.0));
+/// ```
+#[diagnostic::on_unimplemented(message = "`{Self}` cannot be converted element-wise into `{T}`")]
+pub trait TupleElementwiseInto<T>: Tuple
+where
+    T: Tuple
+{
+    fn elementwise_into(self) -> T;
 }
 
-#[cfg(feature = "192")]
-#[cfg(not(feature = "dont_hurt_yourself_by_using_all_features"))]
-#[cfg(not(feature = "224"))]
-impl_split_all! {
-    (
-        _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16,
-        _17, _18, _19, _20, _21, _22, _23, _24, _25, _26, _27, _28, _29, _30, _31, _32,
-        _33, _34, _35, _36, _37, _38, _39, _40, _41, _42, _43, _44, _45, _46, _47, _48,
-        _49, _50, _51, _52, _53, _54, _55, _56, _57, _58, _59, _60, _61, _62, _63, _64,
-        _65, _66, _67, _68, _69, _70, _71, _72, _73, _74, _75, _76, _77, _78, _79, _80,
-        _81, _82, _83, _84, _85, _86, _87, _88, _89, _90, _91, _92, _93, _94, _95, _96,
-        _97, _98, _99, _100, _101, _102, _103, _104, _105, _106, _107, _108, _109, _110, _111, _112,
-        _113, _114, _115, _116, _117, _118, _119, _120, _121, _122, _123, _124, _125, _126, _127, _128,
-        _129, _130, _131, _132, _133, _134, _135, _136, _137, _138, _139, _140, _141, _142, _143, _144,
-        _145, _146, _147, _148, _149, _150, _151, _152, _153, _154, _155, _156, _157, _158, _159, _160,
-        _161, _162, _163, _164, _165, _166, _167, _168, _169, _170, _171, _172, _173, _174, _175, _176,
-        _177, _178, _179, _180, _181, _182, _183, _184, _185, _186, _187, _188, _189, _190, _191, _192
-    )
+/// Homogeneous tuples, where every element is of type `T`, have the trait [TupleToArray](crate::TupleToArray), which turns them into an array of length `N`.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let a = tuple_split::tuple_to_array((1, 2, 3));
+///
+/// assert_eq!(a, [1, 2, 3]);
+/// ```
+#[diagnostic::on_unimplemented(message = "`{Self}` cannot be turned into an array `[{T}; {N}]`")]
+#[const_trait]
+pub trait TupleToArray<T, const N: usize>: Tuple
+{
+    fn tuple_to_array(self) -> [T; N];
 }
 
-#[cfg(feature = "224")]
-#[cfg(not(feature = "dont_hurt_yourself_by_using_all_features"))]
-#[cfg(not(feature = "256"))]
-impl_split_all! {
-    (
-        _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16,
-        _17, _18, _19, _20, _21, _22, _23, _24, _25, _26, _27, _28, _29, _30, _31, _32,
-        _33, _34, _35, _36, _37, _38, _39, _40, _41, _42, _43, _44, _45, _46, _47, _48,
-        _49, _50, _51, _52, _53, _54, _55, _56, _57, _58, _59, _60, _61, _62, _63, _64,
-        _65, _66, _67, _68, _69, _70, _71, _72, _73, _74, _75, _76, _77, _78, _79, _80,
-        _81, _82, _83, _84, _85, _86, _87, _88, _89, _90, _91, _92, _93, _94, _95, _96,
-        _97, _98, _99, _100, _101, _102, _103, _104, _105, _106, _107, _108, _109, _110, _111, _112,
-        _113, _114, _115, _116, _117, _118, _119, _120, _121, _122, _123, _124, _125, _126, _127, _128,
-        _129, _130, _131, _132, _133, _134, _135, _136, _137, _138, _139, _140, _141, _142, _143, _144,
-        _145, _146, _147, _148, _149, _150, _151, _152, _153, _154, _155, _156, _157, _158, _159, _160,
-        _161, _162, _163, _164, _165, _166, _167, _168, _169, _170, _171, _172, _173, _174, _175, _176,
-        _177, _178, _179, _180, _181, _182, _183, _184, _185, _186, _187, _188, _189, _190, _191, _192,
-        _193, _194, _195, _196, _197, _198, _199, _200, _201, _202, _203, _204, _205, _206, _207, _208,
-        _209, _210, _211, _212, _213, _214, _215, _216, _217, _218, _219, _220, _221, _222, _223, _224
-    )
+/// Arrays have the trait [ArrayToTuple](crate::ArrayToTuple), which turns them into a homogeneous tuple [ArrayToTuple::Tupled](ArrayToTuple::Tupled).
+/// It's the inverse of [TupleToArray](crate::TupleToArray).
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = tuple_split::array_to_tuple([1, 2, 3]);
+///
+/// assert_eq!(t, (1, 2, 3));
+/// ```
+pub trait ArrayToTuple
+{
+    type Tupled: Tuple;
+
+    fn array_to_tuple(self) -> Self::Tupled;
 }
 
-#[cfg(not(feature = "dont_hurt_yourself_by_using_all_features"))]
-#[cfg(feature = "256")]
-impl_split_all! {
-    (
-        _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16,
-        _17, _18, _19, _20, _21, _22, _23, _24, _25, _26, _27, _28, _29, _30, _31, _32,
-        _33, _34, _35, _36, _37, _38, _39, _40, _41, _42, _43, _44, _45, _46, _47, _48,
-        _49, _50, _51, _52, _53, _54, _55, _56, _57, _58, _59, _60, _61, _62, _63, _64,
-        _65, _66, _67, _68, _69, _70, _71, _72, _73, _74, _75, _76, _77, _78, _79, _80,
-        _81, _82, _83, _84, _85, _86, _87, _88, _89, _90, _91, _92, _93, _94, _95, _96,
-        _97, _98, _99, _100, _101, _102, _103, _104, _105, _106, _107, _108, _109, _110, _111, _112,
-        _113, _114, _115, _116, _117, _118, _119, _120, _121, _122, _123, _124, _125, _126, _127, _128,
-        _129, _130, _131, _132, _133, _134, _135, _136, _137, _138, _139, _140, _141, _142, _143, _144,
-        _145, _146, _147, _148, _149, _150, _151, _152, _153, _154, _155, _156, _157, _158, _159, _160,
-        _161, _162, _163, _164, _165, _166, _167, _168, _169, _170, _171, _172, _173, _174, _175, _176,
-        _177, _178, _179, _180, _181, _182, _183, _184, _185, _186, _187, _188, _189, _190, _191, _192,
-        _193, _194, _195, _196, _197, _198, _199, _200, _201, _202, _203, _204, _205, _206, _207, _208,
-        _209, _210, _211, _212, _213, _214, _215, _216, _217, _218, _219, _220, _221, _222, _223, _224,
-        _225, _226, _227, _228, _229, _230, _231, _232, _233, _234, _235, _236, _237, _238, _239, _240,
-        _241, _242, _243, _244, _245, _246, _247, _248, _249, _250, _251, _252, _253, _254, _255, _256
-    )
+/// A type-level predicate over element types. [ElementPredicate::MATCHES](ElementPredicate::MATCHES) tells whether the element type `T` satisfies the predicate.
+///
+/// Since there's no specialization, a predicate must be implemented for every element type it's used on, including the ones it doesn't match.
+///
+/// # Example
+///
+/// ```rust
+/// use tuple_split::ElementPredicate;
+///
+/// struct IsInteger;
+///
+/// impl ElementPredicate<u8> for IsInteger
+/// {
+///     const MATCHES: bool = true;
+/// }
+/// impl ElementPredicate<f32> for IsInteger
+/// {
+///     const MATCHES: bool = false;
+/// }
+///
+/// assert!(<IsInteger as ElementPredicate<u8>>::MATCHES);
+/// assert!(!<IsInteger as ElementPredicate<f32>>::MATCHES);
+/// ```
+pub trait ElementPredicate<T>
+{
+    const MATCHES: bool;
 }
 
-#[cfg(test)]
-mod tests
+/// Tuples whose element types all have the [ElementPredicate](crate::ElementPredicate) `P` have the trait [TupleTakeWhile](crate::TupleTakeWhile),
+/// where [TupleTakeWhile::COUNT](TupleTakeWhile::COUNT) is the length of the longest leading run of elements matching `P`.
+#[diagnostic::on_unimplemented(message = "`{P}` is not an `ElementPredicate` for every element of `{Self}`")]
+pub trait TupleTakeWhile<P>: Tuple
 {
-    use crate as tuple_split;
+    const COUNT: usize;
+}
 
-    #[test]
-    fn test_split_concat()
-    {
-        let t: (u8, f32, &str) = (1, 1.0, "test");
+/// A type-level predicate over element positions and types. [PositionPredicate::MATCHES](PositionPredicate::MATCHES) tells whether the element of type `T`
+/// at index `INDEX` satisfies the predicate.
+///
+/// Unlike [ElementPredicate](crate::ElementPredicate), it can look at where an element is, not just what it is. Like it, it must be implemented for every element
+/// type and index it's used on, which is easiest with a blanket implementation.
+///
+/// # Example
+///
+/// ```rust
+/// use tuple_split::PositionPredicate;
+///
+/// struct AtLeastTwo;
+///
+/// impl<T, const INDEX: usize> PositionPredicate<T, INDEX> for AtLeastTwo
+/// {
+///     const MATCHES: bool = INDEX >= 2;
+/// }
+///
+/// assert!(!<AtLeastTwo as PositionPredicate<u8, 1>>::MATCHES);
+/// assert!(<AtLeastTwo as PositionPredicate<u8, 2>>::MATCHES);
+/// ```
+pub trait PositionPredicate<T, const INDEX: usize>
+{
+    const MATCHES: bool;
+}
 
-        let (l, r) = tuple_split::split_tuple_at::<0, _>(t);
-        assert_eq!(t, tupleops::concat_tuples(l, r));
+/// Tuples whose elements all have the [PositionPredicate](crate::PositionPredicate) `P` have the trait [TupleFindFirst](crate::TupleFindFirst),
+/// where [TupleFindFirst::INDEX](TupleFindFirst::INDEX) is the index of the first element matching `P`, or the length of the tuple if none do.
+#[diagnostic::on_unimplemented(message = "`{P}` is not a `PositionPredicate` for every element of `{Self}`")]
+pub trait TupleFindFirst<P>: Tuple
+{
+    const INDEX: usize;
+}
 
-        let (l, r) = tuple_split::split_tuple_at::<1, _>(t);
-        assert_eq!(t, tupleops::concat_tuples(l, r));
+/// Tuples which are made up of a left part `L` followed by a right part `R` have the trait [TupleRejoin](crate::TupleRejoin),
+/// which joins `L` and `R` back together into `Self`. It's the inverse of [TupleSplitInto](crate::TupleSplitInto).
+///
+/// # Example
+///
+/// ```rust
+/// use tuple_split::TupleRejoin;
+///
+/// let t = <(u8, f32, &str)>::rejoin((1, 1.0), ("test",));
+///
+/// assert_eq!(t, (1, 1.0, "test"));
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{L}` and `{R}` cannot be rejoined into `{Self}`",
+    label = "the halves don't line up with `{Self}`",
+    note = "was the tuple split at the wrong index?"
+)]
+#[cfg(feature = "rejoin")]
+#[const_trait]
+pub trait TupleRejoin<L, R>: Tuple
+where
+    L: Tuple,
+    R: Tuple
+{
+    fn rejoin(left: L, right: R) -> Self;
+}
 
-        let (l, r) = tuple_split::split_tuple_at::<2, _>(t);
-        assert_eq!(t, tupleops::concat_tuples(l, r));
+/// Tuples have the trait [TupleRefs](crate::TupleRefs), which borrows each of their elements individually,
+/// as a tuple of references [TupleRefs::Refs](TupleRefs::Refs) or a tuple of mutable references [TupleRefs::Muts](TupleRefs::Muts).
+///
+/// # Example
+///
+/// ```rust
+/// use tuple_split::TupleRefs;
+///
+/// let mut t = (1, 1.0, "test");
+///
+/// assert_eq!(t.as_refs(), (&1, &1.0, &"test"));
+///
+/// *t.as_muts().0 += 1;
+/// assert_eq!(t, (2, 1.0, "test"));
+/// ```
+///
+/// The tuples of references are the same types as [tupleops::RefTuple](tupleops::RefTuple) and [tupleops::RefMutTuple](tupleops::RefMutTuple),
+/// and are made with [tupleops::ref_tuple](tupleops::ref_tuple) and [tupleops::ref_mut_tuple](tupleops::ref_mut_tuple). So halves split by reference can be passed
+/// straight on to `tupleops`. This trait is kept because using those aliases generically takes a higher-ranked bound like `for<'a> &'a T: TupleRef<'a, T>`,
+/// while a generic associated type takes no extra bounds.
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// use tuple_split::TupleRefs;
+///
+/// let t = (1, 1.0, "test");
+///
+/// let (l, r): (tupleops::RefTuple<'_, (i32, f64)>, tupleops::RefTuple<'_, (&str,)>) = tuple_split::split_tuple_at::<2, _>(t.as_refs());
+///
+/// assert_eq!(tupleops::concat_tuples(l, r), tupleops::ref_tuple(&t));
+/// ```
+pub trait TupleRefs: Tuple
+{
+    type Refs<'a>: Tuple
+    where
+        Self: 'a;
+    type Muts<'a>: Tuple
+    where
+        Self: 'a;
 
-        let (l, r) = tuple_split::split_tuple_at::<3, _>(t);
-        assert_eq!(t, tupleops::concat_tuples(l, r));
-    }
+    fn as_refs(&self) -> Self::Refs<'_>;
+    fn as_muts(&mut self) -> Self::Muts<'_>;
+}
 
-    #[cfg(feature = "8")]
-    #[test]
-    fn test()
+split_at_diagnostic! {
+    /// Tuples which may be split at index `MIDDLE` while borrowed have the trait [TupleSplitAtRef](crate::TupleSplitAtRef),
+    /// which, when split, returns tuples of references to the elements of [SplitTypeAt::Left](SplitTypeAt::Left) and [SplitTypeAt::Right](SplitTypeAt::Right).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    ///
+    /// let t = (1, 1.0, "test");
+    ///
+    /// let (l, r) = tuple_split::split_tuple_at_ref::<2, _>(&t);
+    ///
+    /// assert_eq!(l, (&1, &1.0));
+    /// assert_eq!(r, (&"test",));
+    /// ```
+    #[cfg(feature = "refs")]
+    pub trait TupleSplitAtRef<const MIDDLE: usize>: TupleSplitAt<MIDDLE, Left: TupleRefs, Right: TupleRefs>
     {
-        let t = (1u8, 2u16, 3u32, 4u64, 5u128);
+        /// Splits into references to each half, where the halves may have different lifetimes.
+        ///
+        /// This is sound, since both halves are shared borrows of `self`, which outlives them both. No element is borrowed mutably, so the halves can't alias anything they
+        /// shouldn't, no matter how long each of them lives.
+        fn split_tuple_at_ref_lt<'a, 'l, 'r>(&'a self) -> (LeftRefs<'l, Self, MIDDLE>, RightRefs<'r, Self, MIDDLE>)
+        where
+            'a: 'l + 'r;
+        /// Splits into mutable references to each half.
+        ///
+        /// Both halves share the lifetime of the borrow, since they're made from the same exclusive borrow of `self`.
+        fn split_tuple_at_mut(&mut self) -> (LeftMuts<'_, Self, MIDDLE>, RightMuts<'_, Self, MIDDLE>);
+    }
+}
 
-        let (l1, r1) = tuple_split::split_tuple_into_left::<(u8, u16), _>(t);
-        let (l2, r2) = tuple_split::split_tuple_into_right::<(u32, u64, u128), _>(t);
+/// Tuples which may be split at index `MIDDLE` while pinned have the trait [TupleSplitAtPin](crate::TupleSplitAtPin),
+/// which, when split, returns tuples of pinned mutable references to the elements of [SplitTypeAt::Left](SplitTypeAt::Left) and
+/// [SplitTypeAt::Right](SplitTypeAt::Right).
+#[cfg(feature = "futures")]
+#[diagnostic::on_unimplemented(message = "`{Self}` cannot be split at index `{MIDDLE}` while pinned")]
+pub trait TupleSplitAtPin<const MIDDLE: usize>: TupleSplitAt<MIDDLE>
+{
+    type LeftPins<'a>: Tuple
+    where
+        Self: 'a;
+    type RightPins<'a>: Tuple
+    where
+        Self: 'a;
+
+    /// Splits into pinned mutable references to each element of each half.
+    ///
+    /// Pinning is structural for every element of a tuple. Tuples have no [Drop](core::ops::Drop) implementation of their own, aren't `#[repr(packed)]`,
+    /// and are only [Unpin](core::marker::Unpin) if all of their elements are. So no element can be moved out of the pinned tuple through the halves.
+    fn split_tuple_at_pin(self: Pin<&mut Self>) -> (Self::LeftPins<'_>, Self::RightPins<'_>);
+}
+
+/// Tuples where every element is a [Future](core::future::Future) have the trait [TupleFutures](crate::TupleFutures).
+#[cfg(feature = "futures")]
+#[diagnostic::on_unimplemented(message = "not every element of `{Self}` is a future")]
+pub trait TupleFutures: Tuple {}
+
+/// Fails to compile if the tuple `T` does not have exactly `EXPECTED` elements.
+///
+/// Put it in front of a split to document and enforce the arity it assumes, so that a tuple which changes arity upstream won't silently produce surprising halves.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = (1, 1.0, "test");
+///
+/// tuple_split::assert_tuple_len::<(u8, f32, &str), 3>();
+/// let (l, r) = tuple_split::split_tuple_at::<2, _>(t);
+///
+/// assert_eq!(t, tupleops::concat_tuples(l, r));
+/// ```
+///
+/// ```rust,compile_fail
+/// tuple_split::assert_tuple_len::<(u8, f32, &str), 2>();
+/// ```
+pub const fn assert_tuple_len<T, const EXPECTED: usize>()
+where
+    T: TupleLen
+{
+    private::AssertTupleLen::<T, EXPECTED>::ASSERTION
+}
+
+/// Returns the size and alignment of each element of the tuple `T`, in order. See [TupleShape](crate::TupleShape).
+///
+/// Useful for reasoning about the layout of the halves before splitting.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// const SHAPE: &[(usize, usize)] = tuple_split::tuple_shape::<(u8, u16, u64)>();
+///
+/// assert_eq!(SHAPE, &[(1, 1), (2, 2), (8, 8)]);
+/// assert_eq!(SHAPE.len(), 3);
+/// ```
+pub const fn tuple_shape<T>() -> &'static [(usize, usize)]
+where
+    T: TupleShape
+{
+    T::SHAPE
+}
+
+/// A fluent wrapper around a tuple, which can be split in any of the ways the free functions allow.
+///
+/// Holds the tuple by value, and each terminal method consumes it to return the two halves. It is `#[repr(transparent)]`, so it costs nothing beyond the tuple
+/// itself.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// use tuple_split::Splitter;
+///
+/// let t = (1, 1.0, "test");
+///
+/// let (l, r) = Splitter::new(t).at::<2>();
+/// assert_eq!(t, tupleops::concat_tuples(l, r));
+///
+/// let (l, r) = Splitter::new(t).into_left::<(u8, f32)>();
+/// assert_eq!(t, tupleops::concat_tuples(l, r));
+///
+/// let (l, r) = Splitter::new(t).into_right::<(&str,)>();
+/// assert_eq!(t, tupleops::concat_tuples(l, r));
+///
+/// let (l, r) = Splitter::new(t).into::<(u8, f32), (&str,)>();
+/// assert_eq!(t, tupleops::concat_tuples(l, r));
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Splitter<T>(T)
+where
+    T: Tuple;
+
+impl<T> Splitter<T>
+where
+    T: Tuple
+{
+    /// Wraps a tuple, so that it can be split.
+    pub const fn new(tuple: T) -> Self
+    {
+        Self(tuple)
+    }
+
+    /// Unwraps the tuple without splitting it.
+    pub const fn into_inner(self) -> T
+    {
+        let Self(tuple) = self;
+        tuple
+    }
+
+    /// Splits the tuple at index `MIDDLE`. Equivalent to [split_tuple_at](crate::split_tuple_at).
+    pub const fn at<const MIDDLE: usize>(self) -> (T::Left, T::Right)
+    where
+        T: ~const TupleSplitAt<MIDDLE>
+    {
+        self.into_inner().split_tuple_at()
+    }
+
+    /// Splits the tuple into the parts `L` and `R`. Equivalent to [split_tuple_into](crate::split_tuple_into).
+    pub const fn into<L, R>(self) -> (L, R)
+    where
+        T: ~const TupleSplitInto<L, R>,
+        L: Tuple,
+        R: Tuple
+    {
+        self.into_inner().split_tuple_into()
+    }
+
+    /// Splits the tuple given a left part `L`. Equivalent to [split_tuple_into_left](crate::split_tuple_into_left).
+    pub const fn into_left<L>(self) -> (L, T::Right)
+    where
+        T: ~const TupleSplitIntoLeft<L>,
+        L: Tuple
+    {
+        self.into_inner().split_tuple_into_left()
+    }
+
+    /// Splits the tuple given a right part `R`. Equivalent to [split_tuple_into_right](crate::split_tuple_into_right).
+    pub const fn into_right<R>(self) -> (T::Left, R)
+    where
+        T: ~const TupleSplitIntoRight<R>,
+        R: Tuple
+    {
+        self.into_inner().split_tuple_into_right()
+    }
+}
+
+/// The two halves of a split tuple, along with the number of elements in each half and in the whole tuple.
+///
+/// Returned by [split_tuple_at_info](crate::split_tuple_at_info). Handy for logging and assertions, without having to look up the arities separately.
+/// The lengths follow from the types of the halves, so they're associated constants rather than fields.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SplitInfo<L, R>
+where
+    L: Tuple,
+    R: Tuple
+{
+    pub left: L,
+    pub right: R
+}
+
+impl<L, R> SplitInfo<L, R>
+where
+    L: TupleLen,
+    R: TupleLen
+{
+    /// The number of elements in the left half.
+    pub const LEFT_LEN: usize = L::LEN;
+    /// The number of elements in the right half.
+    pub const RIGHT_LEN: usize = R::LEN;
+    /// The number of elements in the whole tuple.
+    pub const TOTAL_LEN: usize = L::LEN + R::LEN;
+}
+
+/// The left part `L` and right part `R` of a split tuple, as a named type instead of a pair.
+///
+/// Returned by the `*_parts` variants of the split functions, like [split_tuple_at_parts](crate::split_tuple_at_parts).
+/// It converts to and from a pair `(L, R)`, so the result of any other split can be turned into one too.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = (1, 1.0, "test");
+///
+/// let parts = tuple_split::split_tuple_at_parts::<2, _>(t);
+///
+/// assert_eq!(parts.left, (1, 1.0));
+/// assert_eq!(parts.right, ("test",));
+/// assert_eq!(parts.rejoin(), t);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Parts<L, R>
+where
+    L: Tuple,
+    R: Tuple
+{
+    pub left: L,
+    pub right: R
+}
+
+impl<L, R> Parts<L, R>
+where
+    L: Tuple,
+    R: Tuple
+{
+    pub const fn from_parts(left: L, right: R) -> Self
+    {
+        Self { left, right }
+    }
+
+    pub const fn into_parts(self) -> (L, R)
+    {
+        let Self { left, right } = self;
+        (left, right)
+    }
+
+    /// Joins the parts back together into one tuple.
+    pub fn rejoin(self) -> ConcatTuples<L, R>
+    where
+        (L, R): TupleConcat<L, R>
+    {
+        tupleops::concat_tuples(self.left, self.right)
+    }
+}
+
+impl<L, R> From<(L, R)> for Parts<L, R>
+where
+    L: Tuple,
+    R: Tuple
+{
+    fn from((left, right): (L, R)) -> Self
+    {
+        Self::from_parts(left, right)
+    }
+}
+
+impl<L, R> From<Parts<L, R>> for (L, R)
+where
+    L: Tuple,
+    R: Tuple
+{
+    fn from(parts: Parts<L, R>) -> Self
+    {
+        parts.into_parts()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<L, R> serde::Serialize for Parts<L, R>
+where
+    L: Tuple + serde::Serialize,
+    R: Tuple + serde::Serialize
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer
+    {
+        (&self.left, &self.right).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, L, R> serde::Deserialize<'de> for Parts<L, R>
+where
+    L: Tuple + serde::Deserialize<'de>,
+    R: Tuple + serde::Deserialize<'de>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        <(L, R)>::deserialize(deserializer).map(Self::from)
+    }
+}
+
+/// Zero-sized evidence that a tuple was split at index `MIDDLE`.
+///
+/// Returned by [split_ref_with_proof](crate::split_ref_with_proof), so that the owned tuple can later be split at exactly the same index,
+/// without having to repeat the index.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SplitIndex<const MIDDLE: usize>;
+
+impl<const MIDDLE: usize> SplitIndex<MIDDLE>
+{
+    pub const fn new() -> Self
+    {
+        Self
+    }
+
+    /// Splits tuple at the index this is evidence of. Equivalent to [split_tuple_at](crate::split_tuple_at)`::<MIDDLE, _>`.
+    pub const fn split_tuple_at<T>(self, tuple: T) -> (T::Left, T::Right)
+    where
+        T: ~const TupleSplitAt<MIDDLE>
+    {
+        tuple.split_tuple_at()
+    }
+}
+
+impl<const MIDDLE: usize> ConstUsize for SplitIndex<MIDDLE>
+{
+    const VALUE: usize = MIDDLE;
+}
+
+/// A range of elements `START..END` to split out of a tuple, as a value. Used by [split_range](crate::split_range).
+///
+/// Lets a range be worked out once, and then passed around and applied to several tuples.
+/// The range may be empty, but `START` must not be past `END`, which is checked when the range is constructed.
+///
+/// ```rust,compile_fail,E0080
+/// #![feature(generic_const_exprs)]
+///
+/// let range = tuple_split::SplitRange::<3, 1>::new();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SplitRange<const START: usize, const END: usize>(());
+
+impl<const START: usize, const END: usize> SplitRange<START, END>
+{
+    const ASSERTION: () = assert!(START <= END, "a split range must not start past its end");
+
+    pub const fn new() -> Self
+    {
+        let () = Self::ASSERTION;
+        Self(())
+    }
+}
+
+impl<const START: usize, const END: usize> Default for SplitRange<START, END>
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+/// The error returned when splitting a tuple at an index only known at runtime, and the index is past the end of the tuple.
+///
+/// Returned by [try_split_at_iter](crate::try_split_at_iter). It implements [Display](core::fmt::Display) and [Error](core::error::Error) without needing `std`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SplitError
+{
+    /// The index that was asked for.
+    pub index: usize,
+    /// The number of elements in the tuple.
+    pub len: usize
+}
+
+impl core::fmt::Display for SplitError
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        write!(f, "cannot split a tuple of length {} at index {}", self.len, self.index)
+    }
+}
+
+impl core::error::Error for SplitError {}
+
+/// Tuples which can be split at an index only known at runtime, with [split_erased_at](crate::split_erased_at).
+///
+/// Every tuple where each element implements [Debug](core::fmt::Debug) and is `'static` has it, since the elements are boxed as `dyn Debug`.
+/// The trait is sealed, so it can't be implemented for any other types.
+#[cfg(feature = "alloc")]
+pub trait TupleErase: private::SealedTupleErase {}
+
+#[cfg(feature = "alloc")]
+impl<T> TupleErase for T where T: private::SealedTupleErase {}
+
+/// A half of a split tuple, with its element types erased. Returned by [split_erased_at](crate::split_erased_at).
+///
+/// Lets code carry split halves around without knowing their concrete types, like in plugins.
+#[cfg(feature = "alloc")]
+pub trait SplitHalf
+{
+    /// The number of elements in the half.
+    fn len(&self) -> usize;
+
+    /// Whether the half has no elements.
+    fn is_empty(&self) -> bool
+    {
+        self.len() == 0
+    }
+
+    /// Formats the half like a tuple of its elements would be formatted with [Debug](core::fmt::Debug).
+    fn debug_fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result;
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Debug for dyn SplitHalf
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        self.debug_fmt(f)
+    }
+}
+
+/// Either an owned tuple, or a tuple of references borrowing each element of one, like [Cow](https://doc.rust-lang.org/std/borrow/enum.Cow.html) for tuples.
+///
+/// Used by [split_tuple_at_cow](crate::split_tuple_at_cow), which splits owned tuples into owned halves, and borrowed tuples into borrowed halves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SplitCow<'a, T>
+where
+    T: TupleRefs + 'a
+{
+    Owned(T),
+    Borrowed(T::Refs<'a>)
+}
+
+impl<'a, T> SplitCow<'a, T>
+where
+    T: TupleRefs + 'a
+{
+    /// Borrows each element of a tuple.
+    pub fn borrowed(tuple: &'a T) -> Self
+    {
+        Self::Borrowed(tuple.as_refs())
+    }
+
+    /// Returns `true` if the tuple is owned.
+    pub const fn is_owned(&self) -> bool
+    {
+        matches!(self, Self::Owned(_))
+    }
+
+    /// Returns `true` if the tuple is borrowed.
+    pub const fn is_borrowed(&self) -> bool
+    {
+        matches!(self, Self::Borrowed(_))
+    }
+}
+
+/// Defines named split points for a fixed tuple layout.
+///
+/// Takes a type alias for the tuple, followed by a list of split points. Each split point becomes a constant holding its index, and a function which splits the tuple
+/// there using [split_tuple_at](crate::split_tuple_at). This lets code which works on a fixed record layout refer to its splits by name, and keeps the indices
+/// in one place.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// tuple_split::split_table! {
+///     type Record = (u8, u16, &'static str, f32);
+///
+///     pub const HEADER: usize = 2 => pub fn header_split;
+///     pub const BODY: usize = 3 => pub fn body_split;
+/// }
+///
+/// let record: Record = (1, 2, "body", 4.0);
+///
+/// assert_eq!(HEADER, 2);
+/// assert_eq!(header_split(record), ((1, 2), ("body", 4.0)));
+/// assert_eq!(body_split(record), ((1, 2, "body"), (4.0,)));
+/// ```
+#[macro_export]
+macro_rules! split_table {
+    (
+        $tvis:vis type $tuple:ident = $ty:ty;
+
+        $($cvis:vis const $index:ident: usize = $middle:expr => $fvis:vis fn $split:ident;)*
+    ) => {
+        $tvis type $tuple = $ty;
+
+        $(
+            $cvis const $index: usize = $middle;
+
+            $fvis fn $split(tuple: $tuple) -> ($crate::Left<$tuple, { $index }>, $crate::Right<$tuple, { $index }>)
+            {
+                $crate::split_tuple_at::<{ $index }, $tuple>(tuple)
+            }
+        )*
+    };
+}
+
+/// Builds a tuple `T` at runtime by pushing its elements one at a time, in order.
+///
+/// Since the type of a tuple can't grow at runtime, the accumulator stores each element of `T` as an [Option](core::option::Option), and checks the type
+/// of each pushed value against the next element. Once every element has been pushed, it can be finished into `T`, or split right away.
+/// This is useful for parsers which discover the fields of a record one at a time.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// use tuple_split::TupleAccumulator;
+///
+/// let mut acc = TupleAccumulator::<(u8, &str, f32)>::new();
+///
+/// acc.push_back(1u8).unwrap();
+/// acc.push_back("test").unwrap();
+/// assert_eq!(acc.push_back(1u8), Err(1u8));
+/// acc.push_back(1.0f32).unwrap();
+///
+/// assert_eq!(acc.finish_split::<1>(), Ok(((1,), ("test", 1.0))));
+/// ```
+pub struct TupleAccumulator<T>
+where
+    T: TupleOptions
+{
+    options: T::Options,
+    len: usize
+}
+
+impl<T> TupleAccumulator<T>
+where
+    T: TupleOptions
+{
+    /// Makes an accumulator where no element has been pushed yet.
+    pub fn new() -> Self
+    {
+        Self {
+            options: T::empty_options(),
+            len: 0
+        }
+    }
+
+    /// The number of elements pushed so far.
+    pub const fn len(&self) -> usize
+    {
+        self.len
+    }
+
+    /// Returns `true` if no element has been pushed yet.
+    pub const fn is_empty(&self) -> bool
+    {
+        self.len == 0
+    }
+
+    /// Returns `true` if every element of `T` has been pushed.
+    pub const fn is_complete(&self) -> bool
+    {
+        self.len == T::LEN
+    }
+
+    /// Pushes the next element of `T`.
+    ///
+    /// Gives back the value if it's not of the type of the next element, or if the accumulator is already complete.
+    pub fn push_back<V>(&mut self, value: V) -> Result<(), V>
+    where
+        V: 'static
+    {
+        match T::option_slot(&mut self.options, self.len).and_then(|slot| slot.downcast_mut::<Option<V>>())
+        {
+            Some(slot) =>
+            {
+                *slot = Some(value);
+                self.len += 1;
+                Ok(())
+            }
+            None => Err(value)
+        }
+    }
+
+    /// Finishes the tuple, or gives back the accumulator if some elements haven't been pushed yet.
+    pub fn finish(self) -> Result<T, Self>
+    {
+        let len = self.len;
+        T::try_from_options(self.options).map_err(|options| Self { options, len })
+    }
+
+    /// Finishes the tuple and splits it at index `MIDDLE`, or gives back the accumulator if some elements haven't been pushed yet.
+    pub fn finish_split<const MIDDLE: usize>(self) -> Result<(T::Left, T::Right), Self>
+    where
+        T: TupleSplitAt<MIDDLE>
+    {
+        self.finish().map(TupleSplitAt::split_tuple_at)
+    }
+}
+
+impl<T> Default for TupleAccumulator<T>
+where
+    T: TupleOptions
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<T> core::fmt::Debug for TupleAccumulator<T>
+where
+    T: TupleOptions<Options: core::fmt::Debug>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        f.debug_struct("TupleAccumulator").field("options", &self.options).field("len", &self.len).finish()
+    }
+}
+
+impl<T> PartialEq for TupleAccumulator<T>
+where
+    T: TupleOptions<Options: PartialEq>
+{
+    fn eq(&self, other: &Self) -> bool
+    {
+        self.len == other.len && self.options == other.options
+    }
+}
+
+macro_rules! impl_split_single {
+    (( $($types1:ident),* ), ( $($types2:ident),* )) => {
+        impl<$($types1,)* $($types2,)*> SplitTypeAt<{count!($($types1),*)}> for ($($types1,)* $($types2,)*)
+        {
+            type Left = ($($types1,)*);
+            type Right = ($($types2,)*);
+        }
+
+        impl<$($types1,)* $($types2,)*> const TupleSplitAt<{count!($($types1),*)}> for ($($types1,)* $($types2,)*)
+        {
+            #[inline(always)]
+            fn split_tuple_at(self) -> (Self::Left, Self::Right)
+            {
+                let ($($types1,)* $($types2,)*) = self;
+                (($($types1,)*), ($($types2,)*))
+            }
+        }
+
+        // The elements stand in for the generic ones, with sizes that are multiples of the largest alignment, so that the sizes of the halves can be checked.
+        #[cfg(any(test, feature = "verify"))]
+        const _: () = {
+            let () = private::VerifySplit::<($(replace_ty!($types1 => u8),)* $(replace_ty!($types2 => [u8; 3]),)*), {count!($($types1),*)}>::ASSERTION;
+            let () = private::VerifySplit::<($(replace_ty!($types1 => u32),)* $(replace_ty!($types2 => [u16; 2]),)*), {count!($($types1),*)}>::ASSERTION;
+            #[cfg(feature = "array_split")]
+            let () = private::VerifyArraySplit::<{count!($($types1),*) + count!($($types2),*)}, {count!($($types1),*)}>::ASSERTION;
+        };
+
+        #[cfg(feature = "refs")]
+        impl<$($types1,)* $($types2,)*> TupleSplitAtRef<{count!($($types1),*)}> for ($($types1,)* $($types2,)*)
+        {
+            #[inline(always)]
+            fn split_tuple_at_ref_lt<'a, 'l, 'r>(&'a self) -> (($(&'l $types1,)*), ($(&'r $types2,)*))
+            where
+                'a: 'l + 'r
+            {
+                let ($($types1,)* $($types2,)*) = self;
+                (($($types1,)*), ($($types2,)*))
+            }
+            #[inline(always)]
+            fn split_tuple_at_mut(&mut self) -> (($(&mut $types1,)*), ($(&mut $types2,)*))
+            {
+                let ($($types1,)* $($types2,)*) = self;
+                (($($types1,)*), ($($types2,)*))
+            }
+        }
+
+        #[cfg(feature = "futures")]
+        impl<$($types1,)* $($types2,)*> TupleSplitAtPin<{count!($($types1),*)}> for ($($types1,)* $($types2,)*)
+        {
+            type LeftPins<'a> = ($(Pin<&'a mut $types1>,)*)
+            where
+                Self: 'a;
+            type RightPins<'a> = ($(Pin<&'a mut $types2>,)*)
+            where
+                Self: 'a;
+
+            #[inline(always)]
+            fn split_tuple_at_pin(self: Pin<&mut Self>) -> (Self::LeftPins<'_>, Self::RightPins<'_>)
+            {
+                // SAFETY: Pinning is structural for tuple elements, and the elements are never moved here.
+                unsafe {
+                    let ($($types1,)* $($types2,)*) = self.get_unchecked_mut();
+                    (($(Pin::new_unchecked($types1),)*), ($(Pin::new_unchecked($types2),)*))
+                }
+            }
+        }
+
+        #[cfg(feature = "nested")]
+        impl_nested_split!{($($types1),*), ($($types2),*)}
+
+        #[cfg(feature = "rejoin")]
+        impl<$($types1,)* $($types2,)*> const TupleRejoin<($($types1,)*), ($($types2,)*)> for ($($types1,)* $($types2,)*)
+        {
+            #[inline(always)]
+            #[allow(clippy::unused_unit)]
+            fn rejoin(($($types1,)*): ($($types1,)*), ($($types2,)*): ($($types2,)*)) -> Self
+            {
+                ($($types1,)* $($types2,)*)
+            }
+        }
+
+        impl<$($types1,)* $($types2,)*> const TupleSplitIntoLeft<($($types1,)*)> for ($($types1,)* $($types2,)*)
+        {
+            type Right = ($($types2,)*);
+
+            #[inline(always)]
+            fn split_tuple_into_left(self) -> (($($types1,)*), ($($types2,)*))
+            {
+                let ($($types1,)* $($types2,)*) = self;
+                (($($types1,)*), ($($types2,)*))
+            }
+        }
+        impl<$($types1,)* $($types2,)*> const TupleSplitIntoRight<($($types2,)*)> for ($($types1,)* $($types2,)*)
+        {
+            type Left = ($($types1,)*);
+
+            #[inline(always)]
+            fn split_tuple_into_right(self) -> (($($types1,)*), ($($types2,)*))
+            {
+                let ($($types1,)* $($types2,)*) = self;
+                (($($types1,)*), ($($types2,)*))
+            }
+        }
+    };
+}
+#[cfg(feature = "nested")]
+macro_rules! impl_nested_split {
+    (( $($before:ident),* ), ()) => {};
+    (( $($before:ident),* ), ( $chunk:ident $(, $after:ident)* )) => {
+        impl<$($before,)* $chunk, $($after,)* const OFFSET: usize> private::NestedSplitAt<{count!($($before),*)}, OFFSET> for ($($before,)* $chunk, $($after,)*)
+        where
+            $($before: TupleLen,)*
+            $chunk: TupleSplitAt<OFFSET>,
+            $($after: TupleLen,)*
+        {
+            type Left = ($($before,)* $chunk::Left,);
+            type Right = ($chunk::Right, $($after,)*);
+
+            #[inline(always)]
+            fn split_nested_at(self) -> (Self::Left, Self::Right)
+            {
+                let ($($before,)* $chunk, $($after,)*) = self;
+                let (left, right) = $chunk.split_tuple_at();
+                (($($before,)* left,), (right, $($after,)*))
+            }
+        }
+    };
+}
+macro_rules! replace_ty {
+    ($_ident:ident => $ty:ty) => {
+        $ty
+    };
+}
+macro_rules! replace_expr {
+    ($_ident:ident => $expr:expr) => {
+        $expr
+    };
+}
+macro_rules! impl_elementwise_into {
+    (()) => {
+        impl TupleElementwiseInto<()> for ()
+        {
+            fn elementwise_into(self) {}
+        }
+    };
+    (($t0:ident)) => {
+        impl<$t0, U> TupleElementwiseInto<(U,)> for ($t0,)
+        where
+            $t0: Into<U>
+        {
+            fn elementwise_into(self) -> (U,)
+            {
+                let ($t0,) = self;
+                ($t0.into(),)
+            }
+        }
+    };
+    (($t0:ident, $($types:ident),+)) => {
+        impl<$t0, $($types,)+ U, U0, URest> TupleElementwiseInto<U> for ($t0, $($types,)+)
+        where
+            U: TupleSplitAt<1, Left = (U0,), Right = URest>,
+            URest: Tuple,
+            $t0: Into<U0>,
+            ($($types,)+): TupleElementwiseInto<URest>,
+            ((U0,), URest): TupleConcat<(U0,), URest, Type = U>
+        {
+            fn elementwise_into(self) -> U
+            {
+                let ($t0, $($types,)+) = self;
+                tupleops::concat_tuples(($t0.into(),), ($($types,)+).elementwise_into())
+            }
+        }
+    };
+}
+macro_rules! impl_unwrap_single {
+    (($t0:ident)) => {
+        impl<$t0> const TupleUnwrapSingle for ($t0,)
+        {
+            type Unwrapped = $t0;
+
+            #[inline(always)]
+            fn unwrap_single(self) -> Self::Unwrapped
+            {
+                self.0
+            }
+        }
+    };
+    (($($types:ident),*)) => {
+        impl<$($types,)*> const TupleUnwrapSingle for ($($types,)*)
+        {
+            type Unwrapped = Self;
+
+            #[inline(always)]
+            fn unwrap_single(self) -> Self::Unwrapped
+            {
+                self
+            }
+        }
+    };
+}
+macro_rules! impl_find_first {
+    (($($types:ident),*) [$($before:ident),*] [] [$($bounds:tt)*] [$($matches:expr),*]) => {
+        impl<P, $($types,)*> TupleFindFirst<P> for ($($types,)*)
+        where
+            $($bounds)*
+        {
+            const INDEX: usize = private::first_match(&[$($matches),*]);
+        }
+    };
+    (($($types:ident),*) [$($before:ident),*] [$t0:ident $(, $rest:ident)*] [$($bounds:tt)*] [$($matches:expr),*]) => {
+        impl_find_first!{
+            ($($types),*) [$($before,)* $t0] [$($rest),*]
+            [$($bounds)* P: PositionPredicate<$t0, {count!($($before),*)}>,]
+            [$($matches,)* <P as PositionPredicate<$t0, {count!($($before),*)}>>::MATCHES]
+        }
+    };
+}
+macro_rules! impl_reverse {
+    (($($types:ident),*) [] [$($reversed:ident),*]) => {
+        impl<$($types,)*> const TupleReverse for ($($types,)*)
+        {
+            type Reversed = ($($reversed,)*);
+
+            #[inline(always)]
+            #[allow(clippy::unused_unit)]
+            fn reverse_tuple(self) -> Self::Reversed
+            {
+                let ($($types,)*) = self;
+                ($($reversed,)*)
+            }
+        }
+    };
+    (($($types:ident),*) [$t0:ident $(, $rest:ident)*] [$($reversed:ident),*]) => {
+        impl_reverse!{($($types),*) [$($rest),*] [$t0 $(, $reversed)*]}
+    };
+}
+macro_rules! impl_split_combinations {
+    ( (), ( $($types2:ident),* ) ) => {
+        impl_split_single!{(), ($($types2),*)}
+    };
+    (($t0:ident $(,$types1:ident)* ), ( $($types2:ident),* )) => {
+        impl_split_single!{($t0 $(,$types1)*), ($($types2),*)}
+
+        impl_split_combinations!{($($types1),*), ($t0 $(,$types2)*)}
+    };
+    (($($types:ident),*)) => {
+        impl<$($types,)*> TupleLen for ($($types,)*)
+        {
+            const LEN: usize = count!($($types),*);
+        }
+
+        impl<$($types,)*> TupleOptions for ($($types,)*)
+        where
+            $($types: 'static),*
+        {
+            type Options = ($(Option<$types>,)*);
+
+            #[allow(clippy::unused_unit)]
+            fn empty_options() -> Self::Options
+            {
+                ($(replace_expr!($types => None),)*)
+            }
+
+            fn option_slot(options: &mut Self::Options, index: usize) -> Option<&mut dyn Any>
+            {
+                let ($($types,)*) = options;
+                let slots: [&mut dyn Any; count!($($types),*)] = [$($types),*];
+                slots.into_iter().nth(index)
+            }
+
+            #[allow(unreachable_patterns)]
+            fn try_from_options(options: Self::Options) -> Result<Self, Self::Options>
+            {
+                match options
+                {
+                    ($(Some($types),)*) => Ok(($($types,)*)),
+                    options => Err(options)
+                }
+            }
+        }
+
+        impl<$($types,)*> TupleShape for ($($types,)*)
+        {
+            const SHAPE: &'static [(usize, usize)] = &[$((core::mem::size_of::<$types>(), core::mem::align_of::<$types>())),*];
+        }
+
+        impl<$($types,)*> TupleNested for ($($types,)*)
+        where
+            $($types: TupleLen),*
+        {
+            const CHUNK_LENS: &'static [usize] = &[$($types::LEN),*];
+            const FLAT_LEN: usize = 0 $(+ $types::LEN)*;
+        }
+
+        impl<$($types,)*> TupleRefs for ($($types,)*)
+        {
+            type Refs<'a> = ($(&'a $types,)*)
+            where
+                Self: 'a;
+            type Muts<'a> = ($(&'a mut $types,)*)
+            where
+                Self: 'a;
+
+            fn as_refs(&self) -> Self::Refs<'_>
+            {
+                tupleops::ref_tuple(self)
+            }
+            fn as_muts(&mut self) -> Self::Muts<'_>
+            {
+                tupleops::ref_mut_tuple(self)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<$($types,)*> private::SealedTupleErase for ($($types,)*)
+        where
+            $($types: core::fmt::Debug + 'static),*
+        {
+            fn erase(self) -> alloc::vec::Vec<alloc::boxed::Box<dyn core::fmt::Debug>>
+            {
+                let ($($types,)*) = self;
+                alloc::vec![$(alloc::boxed::Box::new($types) as alloc::boxed::Box<dyn core::fmt::Debug>),*]
+            }
+        }
+
+        #[cfg(feature = "futures")]
+        impl<$($types,)*> TupleFutures for ($($types,)*)
+        where
+            $($types: Future),*
+        {
+        }
+
+        impl<T> const TupleToArray<T, {count!($($types),*)}> for ($(replace_ty!($types => T),)*)
+        {
+            fn tuple_to_array(self) -> [T; count!($($types),*)]
+            {
+                let ($($types,)*) = self;
+                [$($types),*]
+            }
+        }
+
+        impl<T> ArrayToTuple for [T; count!($($types),*)]
+        {
+            type Tupled = ($(replace_ty!($types => T),)*);
+
+            #[allow(clippy::unused_unit)]
+            fn array_to_tuple(self) -> Self::Tupled
+            {
+                let [$($types),*] = self;
+                ($($types,)*)
+            }
+        }
+
+        impl<P, $($types,)*> TupleTakeWhile<P> for ($($types,)*)
+        where
+            $(P: ElementPredicate<$types>),*
+        {
+            const COUNT: usize = private::leading_run(&[$(<P as ElementPredicate<$types>>::MATCHES),*]);
+        }
+
+        impl_elementwise_into!{($($types),*)}
+
+        impl_reverse!{($($types),*) [$($types),*] []}
+
+        impl_unwrap_single!{($($types),*)}
+
+        impl_find_first!{($($types),*) [] [$($types),*] [] []}
+
+        impl_split_combinations!{($($types),*), ()}
+    }
+}
+macro_rules! impl_split_all {
+    (()) => {
+        impl_split_combinations!{()}
+    };
+    (($t0:ident $(,$types:ident)*)) => {
+        impl_split_combinations!{($t0 $(,$types)*)}
+
+        impl<T, const INDICES: &'static [usize]> private::RemoveFirst<INDICES, {count!($t0 $(,$types)*)}> for T
+        where
+            T: private::RemoveThen<{private::removal_index(INDICES, count!($($types),*))}, INDICES, {count!($($types),*)}>
+        {
+            type Remaining = <T as private::RemoveThen<{private::removal_index(INDICES, count!($($types),*))}, INDICES, {count!($($types),*)}>>::Remaining;
+            type Removed = <T as private::RemoveThen<{private::removal_index(INDICES, count!($($types),*))}, INDICES, {count!($($types),*)}>>::Removed;
+
+            fn remove_first(self) -> (Self::Remaining, Self::Removed)
+            {
+                self.remove_then()
+            }
+        }
+
+        impl_split_all!{($($types),*)}
+    }
+}
+
+/// The largest tuple size supported with the currently enabled features.
+///
+/// Tuples of up to this many elements can be split. It follows the size features described in the [crate docs](crate#tuple-sizes),
+/// including the cap of 8 from `dont_hurt_yourself_by_using_all_features`, and the same ladder used to pick which impls to generate.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// assert!(tuple_split::MAX_TUPLE_SIZE >= 4);
+/// ```
+pub const MAX_TUPLE_SIZE: usize = if !cfg!(feature = "8")
+{
+    4
+}
+else if cfg!(feature = "dont_hurt_yourself_by_using_all_features") || !cfg!(feature = "16")
+{
+    8
+}
+else if !cfg!(feature = "32")
+{
+    16
+}
+else if !cfg!(feature = "64")
+{
+    32
+}
+else if !cfg!(feature = "96")
+{
+    64
+}
+else if !cfg!(feature = "128")
+{
+    96
+}
+else if !cfg!(feature = "160")
+{
+    128
+}
+else if !cfg!(feature = "192")
+{
+    160
+}
+else if !cfg!(feature = "224")
+{
+    192
+}
+else if !cfg!(feature = "256")
+{
+    224
+}
+else
+{
+    256
+};
+
+#[cfg(not(feature = "8"))]
+impl_split_all! {
+    (
+        _1, _2, _3, _4
+    )
+}
+
+#[cfg(feature = "8")]
+#[cfg(any(feature = "dont_hurt_yourself_by_using_all_features", not(feature = "16")))]
+impl_split_all! {
+    (
+        _1, _2, _3, _4, _5, _6, _7, _8
+    )
+}
+
+#[cfg(feature = "16")]
+#[cfg(not(feature = "dont_hurt_yourself_by_using_all_features"))]
+#[cfg(not(feature = "32"))]
+impl_split_all! {
+    (
+        _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16
+    )
+}
+
+#[cfg(feature = "32")]
+#[cfg(not(feature = "dont_hurt_yourself_by_using_all_features"))]
+#[cfg(not(feature = "64"))]
+impl_split_all! {
+    (
+        _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16,
+        _17, _18, _19, _20, _21, _22, _23, _24, _25, _26, _27, _28, _29, _30, _31, _32
+    )
+}
+
+#[cfg(feature = "64")]
+#[cfg(not(feature = "dont_hurt_yourself_by_using_all_features"))]
+#[cfg(not(feature = "96"))]
+impl_split_all! {
+    (
+        _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16,
+        _17, _18, _19, _20, _21, _22, _23, _24, _25, _26, _27, _28, _29, _30, _31, _32,
+        _33, _34, _35, _36, _37, _38, _39, _40, _41, _42, _43, _44, _45, _46, _47, _48,
+        _49, _50, _51, _52, _53, _54, _55, _56, _57, _58, _59, _60, _61, _62, _63, _64
+    )
+}
+
+#[cfg(feature = "96")]
+#[cfg(not(feature = "dont_hurt_yourself_by_using_all_features"))]
+#[cfg(not(feature = "128"))]
+impl_split_all! {
+    (
+        _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16,
+        _17, _18, _19, _20, _21, _22, _23, _24, _25, _26, _27, _28, _29, _30, _31, _32,
+        _33, _34, _35, _36, _37, _38, _39, _40, _41, _42, _43, _44, _45, _46, _47, _48,
+        _49, _50, _51, _52, _53, _54, _55, _56, _57, _58, _59, _60, _61, _62, _63, _64,
+        _65, _66, _67, _68, _69, _70, _71, _72, _73, _74, _75, _76, _77, _78, _79, _80,
+        _81, _82, _83, _84, _85, _86, _87, _88, _89, _90, _91, _92, _93, _94, _95, _96
+    )
+}
+
+#[cfg(feature = "128")]
+#[cfg(not(feature = "dont_hurt_yourself_by_using_all_features"))]
+#[cfg(not(feature = "160"))]
+impl_split_all! {
+    (
+        _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16,
+        _17, _18, _19, _20, _21, _22, _23, _24, _25, _26, _27, _28, _29, _30, _31, _32,
+        _33, _34, _35, _36, _37, _38, _39, _40, _41, _42, _43, _44, _45, _46, _47, _48,
+        _49, _50, _51, _52, _53, _54, _55, _56, _57, _58, _59, _60, _61, _62, _63, _64,
+        _65, _66, _67, _68, _69, _70, _71, _72, _73, _74, _75, _76, _77, _78, _79, _80,
+        _81, _82, _83, _84, _85, _86, _87, _88, _89, _90, _91, _92, _93, _94, _95, _96,
+        _97, _98, _99, _100, _101, _102, _103, _104, _105, _106, _107, _108, _109, _110, _111, _112,
+        _113, _114, _115, _116, _117, _118, _119, _120, _121, _122, _123, _124, _125, _126, _127, _128
+    )
+}
+
+#[cfg(feature = "160")]
+#[cfg(not(feature = "dont_hurt_yourself_by_using_all_features"))]
+#[cfg(not(feature = "192"))]
+impl_split_all! {
+    (
+        _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16,
+        _17, _18, _19, _20, _21, _22, _23, _24, _25, _26, _27, _28, _29, _30, _31, _32,
+        _33, _34, _35, _36, _37, _38, _39, _40, _41, _42, _43, _44, _45, _46, _47, _48,
+        _49, _50, _51, _52, _53, _54, _55, _56, _57, _58, _59, _60, _61, _62, _63, _64,
+        _65, _66, _67, _68, _69, _70, _71, _72, _73, _74, _75, _76, _77, _78, _79, _80,
+        _81, _82, _83, _84, _85, _86, _87, _88, _89, _90, _91, _92, _93, _94, _95, _96,
+        _97, _98, _99, _100, _101, _102, _103, _104, _105, _106, _107, _108, _109, _110, _111, _112,
+        _113, _114, _115, _116, _117, _118, _119, _120, _121, _122, _123, _124, _125, _126, _127, _128,
+        _129, _130, _131, _132, _133, _134, _135, _136, _137, _138, _139, _140, _141, _142, _143, _144,
+        _145, _146, _147, _148, _149, _150, _151, _152, _153, _154, _155, _156, _157, _158, _159, _160
+    )
+}
+
+#[cfg(feature = "192")]
+#[cfg(not(feature = "dont_hurt_yourself_by_using_all_features"))]
+#[cfg(not(feature = "224"))]
+impl_split_all! {
+    (
+        _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16,
+        _17, _18, _19, _20, _21, _22, _23, _24, _25, _26, _27, _28, _29, _30, _31, _32,
+        _33, _34, _35, _36, _37, _38, _39, _40, _41, _42, _43, _44, _45, _46, _47, _48,
+        _49, _50, _51, _52, _53, _54, _55, _56, _57, _58, _59, _60, _61, _62, _63, _64,
+        _65, _66, _67, _68, _69, _70, _71, _72, _73, _74, _75, _76, _77, _78, _79, _80,
+        _81, _82, _83, _84, _85, _86, _87, _88, _89, _90, _91, _92, _93, _94, _95, _96,
+        _97, _98, _99, _100, _101, _102, _103, _104, _105, _106, _107, _108, _109, _110, _111, _112,
+        _113, _114, _115, _116, _117, _118, _119, _120, _121, _122, _123, _124, _125, _126, _127, _128,
+        _129, _130, _131, _132, _133, _134, _135, _136, _137, _138, _139, _140, _141, _142, _143, _144,
+        _145, _146, _147, _148, _149, _150, _151, _152, _153, _154, _155, _156, _157, _158, _159, _160,
+        _161, _162, _163, _164, _165, _166, _167, _168, _169, _170, _171, _172, _173, _174, _175, _176,
+        _177, _178, _179, _180, _181, _182, _183, _184, _185, _186, _187, _188, _189, _190, _191, _192
+    )
+}
+
+#[cfg(feature = "224")]
+#[cfg(not(feature = "dont_hurt_yourself_by_using_all_features"))]
+#[cfg(not(feature = "256"))]
+impl_split_all! {
+    (
+        _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16,
+        _17, _18, _19, _20, _21, _22, _23, _24, _25, _26, _27, _28, _29, _30, _31, _32,
+        _33, _34, _35, _36, _37, _38, _39, _40, _41, _42, _43, _44, _45, _46, _47, _48,
+        _49, _50, _51, _52, _53, _54, _55, _56, _57, _58, _59, _60, _61, _62, _63, _64,
+        _65, _66, _67, _68, _69, _70, _71, _72, _73, _74, _75, _76, _77, _78, _79, _80,
+        _81, _82, _83, _84, _85, _86, _87, _88, _89, _90, _91, _92, _93, _94, _95, _96,
+        _97, _98, _99, _100, _101, _102, _103, _104, _105, _106, _107, _108, _109, _110, _111, _112,
+        _113, _114, _115, _116, _117, _118, _119, _120, _121, _122, _123, _124, _125, _126, _127, _128,
+        _129, _130, _131, _132, _133, _134, _135, _136, _137, _138, _139, _140, _141, _142, _143, _144,
+        _145, _146, _147, _148, _149, _150, _151, _152, _153, _154, _155, _156, _157, _158, _159, _160,
+        _161, _162, _163, _164, _165, _166, _167, _168, _169, _170, _171, _172, _173, _174, _175, _176,
+        _177, _178, _179, _180, _181, _182, _183, _184, _185, _186, _187, _188, _189, _190, _191, _192,
+        _193, _194, _195, _196, _197, _198, _199, _200, _201, _202, _203, _204, _205, _206, _207, _208,
+        _209, _210, _211, _212, _213, _214, _215, _216, _217, _218, _219, _220, _221, _222, _223, _224
+    )
+}
+
+#[cfg(not(feature = "dont_hurt_yourself_by_using_all_features"))]
+#[cfg(feature = "256")]
+impl_split_all! {
+    (
+        _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16,
+        _17, _18, _19, _20, _21, _22, _23, _24, _25, _26, _27, _28, _29, _30, _31, _32,
+        _33, _34, _35, _36, _37, _38, _39, _40, _41, _42, _43, _44, _45, _46, _47, _48,
+        _49, _50, _51, _52, _53, _54, _55, _56, _57, _58, _59, _60, _61, _62, _63, _64,
+        _65, _66, _67, _68, _69, _70, _71, _72, _73, _74, _75, _76, _77, _78, _79, _80,
+        _81, _82, _83, _84, _85, _86, _87, _88, _89, _90, _91, _92, _93, _94, _95, _96,
+        _97, _98, _99, _100, _101, _102, _103, _104, _105, _106, _107, _108, _109, _110, _111, _112,
+        _113, _114, _115, _116, _117, _118, _119, _120, _121, _122, _123, _124, _125, _126, _127, _128,
+        _129, _130, _131, _132, _133, _134, _135, _136, _137, _138, _139, _140, _141, _142, _143, _144,
+        _145, _146, _147, _148, _149, _150, _151, _152, _153, _154, _155, _156, _157, _158, _159, _160,
+        _161, _162, _163, _164, _165, _166, _167, _168, _169, _170, _171, _172, _173, _174, _175, _176,
+        _177, _178, _179, _180, _181, _182, _183, _184, _185, _186, _187, _188, _189, _190, _191, _192,
+        _193, _194, _195, _196, _197, _198, _199, _200, _201, _202, _203, _204, _205, _206, _207, _208,
+        _209, _210, _211, _212, _213, _214, _215, _216, _217, _218, _219, _220, _221, _222, _223, _224,
+        _225, _226, _227, _228, _229, _230, _231, _232, _233, _234, _235, _236, _237, _238, _239, _240,
+        _241, _242, _243, _244, _245, _246, _247, _248, _249, _250, _251, _252, _253, _254, _255, _256
+    )
+}
+
+mod private
+{
+    use core::marker::{PhantomData, Tuple};
+
+    use tupleops::{ConcatTuples, TupleConcat};
+
+    #[cfg(feature = "alloc")]
+    use alloc::{boxed::Box, vec::Vec};
+    #[cfg(feature = "alloc")]
+    use core::fmt::Debug;
+
+    #[cfg(feature = "nested")]
+    use crate::TupleNested;
+    #[cfg(any(test, feature = "verify"))]
+    use crate::TupleShape;
+    use crate::{TupleChunks, TupleLen, TupleRemoveAt, TupleSplitAt};
+
+    #[cfg(feature = "alloc")]
+    pub trait SealedTupleErase: Tuple
+    {
+        fn erase(self) -> Vec<Box<dyn Debug>>;
+    }
+
+    #[cfg(feature = "alloc")]
+    pub struct ErasedHalf(pub Vec<Box<dyn Debug>>);
+
+    #[cfg(feature = "alloc")]
+    impl crate::SplitHalf for ErasedHalf
+    {
+        fn len(&self) -> usize
+        {
+            self.0.len()
+        }
+
+        fn debug_fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+        {
+            let mut tuple = f.debug_tuple("");
+            for element in &self.0
+            {
+                tuple.field(element);
+            }
+            tuple.finish()
+        }
+    }
+
+    pub trait SealedSplittable<const MIDDLE: usize> {}
+
+    impl<T, const MIDDLE: usize> SealedSplittable<MIDDLE> for T where T: TupleSplitAt<MIDDLE> {}
+
+    #[cfg(feature = "array_split")]
+    impl<E, const N: usize, const MIDDLE: usize> SealedSplittable<MIDDLE> for [E; N] {}
+
+    /// Removes the elements at the first `COUNT` indices in `INDICES`, starting with the last of them, so that the others keep their place.
+    pub trait RemoveFirst<const INDICES: &'static [usize], const COUNT: usize>: Tuple
+    {
+        type Remaining: Tuple;
+        type Removed: Tuple;
+
+        fn remove_first(self) -> (Self::Remaining, Self::Removed);
+    }
+
+    impl<T, const INDICES: &'static [usize]> RemoveFirst<INDICES, 0> for T
+    where
+        T: Tuple
+    {
+        type Remaining = T;
+        type Removed = ();
+
+        fn remove_first(self) -> (Self::Remaining, Self::Removed)
+        {
+            (self, ())
+        }
+    }
+
+    /// Removes the element at `INDEX`, and then the elements at the first `COUNT` indices in `INDICES`.
+    pub trait RemoveThen<const INDEX: usize, const INDICES: &'static [usize], const COUNT: usize>: Tuple
+    {
+        type Remaining: Tuple;
+        type Removed: Tuple;
+
+        fn remove_then(self) -> (Self::Remaining, Self::Removed);
+    }
+
+    impl<T, E, Rest, R, const INDEX: usize, const INDICES: &'static [usize], const COUNT: usize> RemoveThen<INDEX, INDICES, COUNT> for T
+    where
+        T: TupleRemoveAt<INDEX, Removed = E, Remaining: RemoveFirst<INDICES, COUNT, Remaining = Rest, Removed = R>>,
+        Rest: Tuple,
+        R: Tuple,
+        (R, (E,)): TupleConcat<R, (E,), Type: Tuple>
+    {
+        type Remaining = Rest;
+        type Removed = ConcatTuples<R, (E,)>;
+
+        fn remove_then(self) -> (Self::Remaining, Self::Removed)
+        {
+            let (rest, last) = self.remove_at();
+            let (rest, removed) = rest.remove_first();
+            (rest, tupleops::concat_tuples(removed, (last,)))
+        }
+    }
+
+    pub struct AssertTupleLen<T, const EXPECTED: usize>(PhantomData<T>)
+    where
+        T: TupleLen;
+
+    impl<T, const EXPECTED: usize> AssertTupleLen<T, EXPECTED>
+    where
+        T: TupleLen
+    {
+        pub const ASSERTION: () = assert!(T::LEN == EXPECTED, "tuple does not have the expected number of elements");
+    }
+
+    /// Returns `true` if the size of every element is a multiple of the largest alignment of any of them.
+    ///
+    /// Then no element needs padding after it, so a tuple with these elements takes up exactly the sum of their sizes, and so does every part of it.
+    /// Each element is described by its size and alignment, as in [TupleShape::SHAPE].
+    #[cfg(any(test, feature = "verify"))]
+    pub const fn packs_tightly(shape: &[(usize, usize)]) -> bool
+    {
+        let mut align = 1;
+        let mut i = 0;
+        while i < shape.len()
+        {
+            if shape[i].1 > align
+            {
+                align = shape[i].1;
+            }
+            i += 1;
+        }
+        let mut i = 0;
+        while i < shape.len()
+        {
+            if !shape[i].0.is_multiple_of(align)
+            {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Checks that the two halves of splitting `T` at `MIDDLE` have `MIDDLE` and the rest of the elements.
+    ///
+    /// Where the layout permits, that is, where the elements [pack tightly](packs_tightly), it also checks that the halves side by side take up exactly
+    /// as much space as the whole tuple, so nothing was lost or duplicated.
+    #[cfg(any(test, feature = "verify"))]
+    pub struct VerifySplit<T, const MIDDLE: usize>(PhantomData<T>)
+    where
+        T: TupleSplitAt<MIDDLE> + TupleLen + TupleShape;
+
+    #[cfg(any(test, feature = "verify"))]
+    impl<T, const MIDDLE: usize> VerifySplit<T, MIDDLE>
+    where
+        T: TupleSplitAt<MIDDLE> + TupleLen + TupleShape
+    {
+        pub const ASSERTION: () = {
+            assert!(
+                T::Left::LEN == MIDDLE && T::Left::LEN + T::Right::LEN == T::LEN,
+                "the halves of a split don't have the elements of the whole tuple"
+            );
+            assert!(
+                !packs_tightly(T::SHAPE) || size_of::<(T::Left, T::Right)>() == size_of::<T>(),
+                "the halves of a split don't take up as much space as the whole tuple"
+            );
+        };
+    }
+
+    /// Returns `true` if splitting the array `[0, 1, ..., N - 1]` at `MIDDLE` gives `[0, ..., MIDDLE - 1]` and `[MIDDLE, ..., N - 1]`.
+    ///
+    /// Arrays are split by reading the halves out through pointers, which depends on the layout of arrays, unlike splitting tuples.
+    #[cfg(all(feature = "array_split", any(test, feature = "verify")))]
+    pub const fn array_split_keeps_order<const N: usize, const MIDDLE: usize>() -> bool
+    where
+        [(); N - MIDDLE]:
+    {
+        let mut array = [0; N];
+        let mut i = 0;
+        while i < N
+        {
+            array[i] = i;
+            i += 1;
+        }
+        let (left, right) = crate::Splittable::<MIDDLE>::split(array);
+        let mut i = 0;
+        while i < N
+        {
+            if (i < MIDDLE && left[i] != i) || (i >= MIDDLE && right[i - MIDDLE] != i)
+            {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Checks that splitting arrays of `N` elements at `MIDDLE` keeps the elements in order. See [array_split_keeps_order].
+    #[cfg(all(feature = "array_split", any(test, feature = "verify")))]
+    pub struct VerifyArraySplit<const N: usize, const MIDDLE: usize>;
+
+    #[cfg(all(feature = "array_split", any(test, feature = "verify")))]
+    impl<const N: usize, const MIDDLE: usize> VerifyArraySplit<N, MIDDLE>
+    where
+        [(); N - MIDDLE]:
+    {
+        pub const ASSERTION: () = assert!(array_split_keeps_order::<N, MIDDLE>(), "splitting an array doesn't keep its elements in order");
+    }
+
+    pub const fn leading_run(matches: &[bool]) -> usize
+    {
+        let mut count = 0;
+        while count < matches.len() && matches[count]
+        {
+            count += 1;
+        }
+        count
+    }
+
+    pub const fn first_match(matches: &[bool]) -> usize
+    {
+        let mut index = 0;
+        while index < matches.len() && !matches[index]
+        {
+            index += 1;
+        }
+        index
+    }
+
+    /// `0` if there are no chunks left, `1` if the rest fits in the last chunk, and `2` if there are more chunks after this one.
+    ///
+    /// The cases are matched with literals in the impls of [TupleChunksCase], since named constants there would make the impls cyclic.
+    pub const fn chunks_case(len: usize, chunk: usize) -> usize
+    {
+        assert!(chunk != 0, "chunk size must be non-zero");
+        if len == 0
+        {
+            0
+        }
+        else if len <= chunk
+        {
+            1
+        }
+        else
+        {
+            2
+        }
+    }
+
+    pub trait TupleChunksCase<const N: usize, const CASE: usize>: Tuple
+    {
+        type Chunks: Tuple;
+
+        fn chunks_case(self) -> Self::Chunks;
+    }
+
+    impl<T, const N: usize> TupleChunksCase<N, 0> for T
+    where
+        T: Tuple
+    {
+        type Chunks = ();
+
+        fn chunks_case(self) -> Self::Chunks {}
+    }
+
+    impl<T, const N: usize> TupleChunksCase<N, 1> for T
+    where
+        T: Tuple
+    {
+        type Chunks = (T,);
+
+        fn chunks_case(self) -> Self::Chunks
+        {
+            (self,)
+        }
+    }
+
+    impl<T, const N: usize> TupleChunksCase<N, 2> for T
+    where
+        T: TupleSplitAt<N, Right: TupleChunks<N>>,
+        ((T::Left,), <T::Right as TupleChunks<N>>::Chunks): TupleConcat<(T::Left,), <T::Right as TupleChunks<N>>::Chunks, Type: Tuple>
+    {
+        type Chunks = ConcatTuples<(T::Left,), <T::Right as TupleChunks<N>>::Chunks>;
+
+        fn chunks_case(self) -> Self::Chunks
+        {
+            let (left, right) = self.split_tuple_at();
+            tupleops::concat_tuples((left,), right.chunks_tuple())
+        }
+    }
+
+    #[cfg(feature = "nested")]
+    pub trait NestedSplitAt<const CHUNK: usize, const OFFSET: usize>: Tuple
+    {
+        type Left: TupleNested;
+        type Right: TupleNested;
+
+        fn split_nested_at(self) -> (Self::Left, Self::Right);
+    }
+
+    #[cfg(feature = "nested")]
+    /// Finds the chunk and the offset within it, of a flat index into a nested tuple with the given chunk lengths.
+    const fn nested_position(chunk_lens: &[usize], index: usize) -> (usize, usize)
+    {
+        let mut chunk = 0;
+        let mut start = 0;
+        while chunk < chunk_lens.len()
+        {
+            if index <= start + chunk_lens[chunk]
+            {
+                return (chunk, index - start);
+            }
+            start += chunk_lens[chunk];
+            chunk += 1;
+        }
+        panic!("flat index is out of range of the nested tuple")
+    }
+
+    #[cfg(feature = "nested")]
+    pub const fn nested_chunk(chunk_lens: &[usize], index: usize) -> usize
+    {
+        nested_position(chunk_lens, index).0
+    }
+
+    #[cfg(feature = "nested")]
+    pub const fn nested_offset(chunk_lens: &[usize], index: usize) -> usize
+    {
+        nested_position(chunk_lens, index).1
+    }
+
+    pub const fn padded_len(len: usize, target: usize) -> usize
+    {
+        if len < target
+        {
+            target
+        }
+        else
+        {
+            len
+        }
+    }
+
+    pub const fn split_index_in_range(index: usize, len: usize) -> usize
+    {
+        assert!(index <= len, "split index is past the end of the tuple");
+        0
+    }
+
+    pub const fn split_span(first: usize, second: usize) -> usize
+    {
+        assert!(first < second, "split indices must be strictly ascending");
+        second - first
+    }
+
+    pub const fn removal_index(indices: &[usize], i: usize) -> usize
+    {
+        let mut j = 1;
+        while j < indices.len()
+        {
+            assert!(indices[j - 1] < indices[j], "removal indices must be strictly ascending");
+            j += 1;
+        }
+        // Impls for every removal count are considered, so positions past the end must not panic.
+        if i < indices.len()
+        {
+            indices[i]
+        }
+        else
+        {
+            usize::MAX
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate as tuple_split;
+
+    #[test]
+    fn test_split_concat()
+    {
+        let t: (u8, f32, &str) = (1, 1.0, "test");
+
+        let (l, r) = tuple_split::split_tuple_at::<0, _>(t);
+        assert_eq!(t, tupleops::concat_tuples(l, r));
+
+        let (l, r) = tuple_split::split_tuple_at::<1, _>(t);
+        assert_eq!(t, tupleops::concat_tuples(l, r));
+
+        let (l, r) = tuple_split::split_tuple_at::<2, _>(t);
+        assert_eq!(t, tupleops::concat_tuples(l, r));
+
+        let (l, r) = tuple_split::split_tuple_at::<3, _>(t);
+        assert_eq!(t, tupleops::concat_tuples(l, r));
+    }
+
+    #[cfg(feature = "8")]
+    #[test]
+    fn test()
+    {
+        let t = (1u8, 2u16, 3u32, 4u64, 5u128);
+
+        let (l1, r1) = tuple_split::split_tuple_into_left::<(u8, u16), _>(t);
+        let (l2, r2) = tuple_split::split_tuple_into_right::<(u32, u64, u128), _>(t);
         let (l3, r3) = tuple_split::split_tuple_at::<2, _>(t);
 
-        assert_eq!(l1, l2);
-        assert_eq!(l2, l3);
+        assert_eq!(l1, l2);
+        assert_eq!(l2, l3);
+
+        assert_eq!(r1, r2);
+        assert_eq!(r2, r3);
+
+        assert_eq!(t, tupleops::concat_tuples(l1, r1));
+        assert_eq!(t, tupleops::concat_tuples(l2, r2));
+        assert_eq!(t, tupleops::concat_tuples(l3, r3));
+    }
+
+    #[test]
+    fn test_splitter()
+    {
+        use tuple_split::Splitter;
+
+        let t = (1u8, 2u16, 3u32);
+
+        assert_eq!(Splitter::new(t).at::<1>(), ((1,), (2, 3)));
+        assert_eq!(Splitter::new(t).into_left::<(u8, u16)>(), ((1, 2), (3,)));
+        assert_eq!(Splitter::new(t).into_right::<(u16, u32)>(), ((1,), (2, 3)));
+        assert_eq!(Splitter::new(t).into::<(), (u8, u16, u32)>(), ((), t));
+        assert_eq!(Splitter::new(t).into_inner(), t);
+    }
+
+    #[test]
+    fn test_rejoinable()
+    {
+        let t = (1u8, 2u16, 3u32, 4u64);
+
+        let ((a, b), (c, d), rejoin) = tuple_split::split_tuple_at_rejoinable::<2, _>(t);
+        assert_eq!(rejoin((b as u8, a as u16), (d as u32, c as u64)), (2, 1, 4, 3));
+
+        let (l, r, rejoin) = tuple_split::split_tuple_at_rejoinable::<1, _>((1, 2));
+        assert_eq!(rejoin(r, l), (2, 1));
+    }
+
+    #[test]
+    fn test_wrappers()
+    {
+        use core::{cmp::Reverse, num::Wrapping};
+
+        let t = (Wrapping(1u8), Wrapping(2u16));
+
+        assert_eq!(tuple_split::split_tuple_at::<1, _>(t), ((Wrapping(1),), (Wrapping(2),)));
+        assert_eq!(tuple_split::split_tuple_into_left::<(Wrapping<u8>,), _>(t), ((Wrapping(1),), (Wrapping(2),)));
+        assert_eq!(tuple_split::split_tuple_into_right::<(Wrapping<u16>,), _>(t), ((Wrapping(1),), (Wrapping(2),)));
+        assert_eq!(
+            tuple_split::split_tuple_into::<(Wrapping<u8>,), (Wrapping<u16>,)>(t),
+            ((Wrapping(1),), (Wrapping(2),))
+        );
+
+        type Mixed = (Reverse<u8>, Reverse<&'static str>, Wrapping<u32>);
+
+        let t: Mixed = (Reverse(1), Reverse("test"), Wrapping(3));
+
+        let (l, r): (tuple_split::LeftOf<Mixed, (Wrapping<u32>,)>, _) = tuple_split::split_tuple_into_right::<(Wrapping<u32>,), _>(t);
+        assert_eq!(l, (Reverse(1), Reverse("test")));
+        assert_eq!(r, (Wrapping(3),));
+
+        fn first_wrapped<T>(t: T) -> (Wrapping<u8>, tuple_split::RightOf<T, (Wrapping<u8>,)>)
+        where
+            T: tuple_split::TupleSplitIntoLeft<(Wrapping<u8>,)>
+        {
+            let ((first,), rest) = tuple_split::split_tuple_into_left(t);
+            (first, rest)
+        }
+
+        assert_eq!(first_wrapped((Wrapping(1u8), Reverse(2u16), 3u32)), (Wrapping(1), (Reverse(2), 3)));
+    }
+
+    #[test]
+    fn test_len()
+    {
+        use tuple_split::TupleLen;
+
+        assert_eq!(<()>::LEN, 0);
+        assert_eq!(<(u8,)>::LEN, 1);
+        assert_eq!(<(u8, u16, u32, u64)>::LEN, 4);
+
+        tuple_split::assert_tuple_len::<(), 0>();
+        tuple_split::assert_tuple_len::<(u8, u16, u32), 3>();
+    }
+
+    #[test]
+    fn test_split_into_left_as()
+    {
+        use tuple_split::TupleElementwiseInto;
+
+        let t = (1u8, 2u8, "test");
+
+        let (l, r) = tuple_split::split_tuple_into_left_as::<(u32, u32), _>(t);
+        assert_eq!(l, (1u32, 2u32));
+        assert_eq!(r, ("test",));
+
+        let (l, r) = tuple_split::split_tuple_into_left_as::<(), _>(t);
+        assert_eq!(l, ());
+        assert_eq!(r, t);
+
+        let t: (u16, i64, f64) = (1u8, 2i32, 3.0f32).elementwise_into();
+        assert_eq!(t, (1, 2, 3.0));
+    }
+
+    #[cfg(feature = "refs")]
+    #[test]
+    fn test_split_ref()
+    {
+        use tuple_split::{LeftRefs, RightRefs, TupleRefs, TupleSplitAtRef};
+
+        let mut t = (1u8, 2u16, 3u32);
+
+        assert_eq!(t.as_refs(), (&1, &2, &3));
+        assert_eq!(tuple_split::split_tuple_at_ref::<1, _>(&t), ((&1,), (&2, &3)));
+        assert_eq!(tuple_split::split_tuple_at_ref::<3, _>(&t), ((&1, &2, &3), ()));
+
+        let ((a,), (b, c)) = tuple_split::split_tuple_at_mut::<1, _>(&mut t);
+        *a = *c as u8;
+        *b += 1;
+        assert_eq!(t, (3, 3, 3));
+
+        fn keep_left<'a, T>(t: &'a T) -> LeftRefs<'a, T, 2>
+        where
+            T: TupleSplitAtRef<2>
+        {
+            let (left, right): (_, RightRefs<'_, T, 2>) = tuple_split::split_tuple_at_ref_lt(t);
+            drop(right);
+            left
+        }
+        fn keep_right<'a, T>(t: &'a T) -> RightRefs<'a, T, 2>
+        where
+            T: TupleSplitAtRef<2>
+        {
+            let (left, right): (LeftRefs<'_, T, 2>, _) = t.split_tuple_at_ref_lt();
+            drop(left);
+            right
+        }
+
+        assert_eq!(keep_left(&t), (&3, &3));
+        assert_eq!(keep_right(&t), (&3,));
+    }
+
+    #[cfg(feature = "refs")]
+    #[test]
+    fn test_split_ref_flavours()
+    {
+        use tuple_split::TupleRefs;
+
+        let t = (1u8, 2u16, 3u32);
+
+        // A tuple of references to each element of the left half
+        let (l_refs, r_refs): ((&u8,), (&u16, &u32)) = tuple_split::split_tuple_at_ref::<1, _>(&t);
+
+        // A reference to a left half tuple, which has to be moved out first, since `t` does not contain one
+        let (l, r) = tuple_split::split_tuple_at::<1, _>(t);
+        let (l_ref, r_ref): (&(u8,), &(u16, u32)) = (&l, &r);
+
+        assert_eq!(l_refs, l_ref.as_refs());
+        assert_eq!(r_refs, r_ref.as_refs());
+        assert!(core::ptr::eq(l_refs.0, &t.0));
+        assert!(!core::ptr::eq(l_refs.0, &l_ref.0));
+    }
+
+    #[test]
+    fn test_array_halves()
+    {
+        assert_eq!(tuple_split::tuple_to_array::<u8, 0, _>(()), []);
+        assert_eq!(tuple_split::tuple_to_array((1, 2, 3)), [1, 2, 3]);
+
+        assert_eq!(tuple_split::split_halves((1u8, 2u16, 3u32)), ((1,), (2, 3)));
+        assert_eq!(tuple_split::split_halves((1u8, 2u16, 3u32, 4u64)), ((1, 2), (3, 4)));
+
+        assert_eq!(tuple_split::split_into_array_halves((1, 2, 3, 4)), [[1, 2], [3, 4]]);
+        assert_eq!(tuple_split::split_into_array_halves(("a", "b")), [["a"], ["b"]]);
+    }
+
+    #[test]
+    fn test_take_while_matching()
+    {
+        use tuple_split::ElementPredicate;
+
+        struct IsInteger;
+
+        impl ElementPredicate<u8> for IsInteger
+        {
+            const MATCHES: bool = true;
+        }
+        impl ElementPredicate<u16> for IsInteger
+        {
+            const MATCHES: bool = true;
+        }
+        impl ElementPredicate<f32> for IsInteger
+        {
+            const MATCHES: bool = false;
+        }
+        impl ElementPredicate<&str> for IsInteger
+        {
+            const MATCHES: bool = false;
+        }
+
+        assert_eq!(tuple_split::take_while_matching::<IsInteger, _>((1u8, 2u16, 3.0f32, 4u8)), ((1, 2), (3.0, 4)));
+        assert_eq!(tuple_split::take_while_matching::<IsInteger, _>(("a", 1u8)), ((), ("a", 1)));
+        assert_eq!(tuple_split::take_while_matching::<IsInteger, _>((1u8, 2u16)), ((1, 2), ()));
+        assert_eq!(tuple_split::take_while_matching::<IsInteger, _>(()), ((), ()));
+    }
+
+    #[test]
+    fn test_split_option()
+    {
+        let t = (1u8, 2u16, 3u32);
+
+        assert_eq!(tuple_split::split_option_at::<1, _>(Some(t)), (Some((1,)), Some((2, 3))));
+        assert_eq!(tuple_split::split_option_at::<1, _>(None::<(u8, u16, u32)>), (None, None));
+
+        const SPLIT: (Option<(u8,)>, Option<(u16, u32)>) = tuple_split::split_option_at::<1, _>(Some((1, 2, 3)));
+        assert_eq!(SPLIT, (Some((1,)), Some((2, 3))));
+    }
+
+    #[test]
+    fn test_split_result()
+    {
+        let t = (1u8, 2u16, 3u32);
+
+        assert_eq!(tuple_split::split_result_at::<1, _, &str>(Ok(t)), Ok(((1,), (2, 3))));
+        assert_eq!(tuple_split::split_result_at::<1, (u8, u16, u32), _>(Err("error")), Err("error"));
+
+        const SPLIT: Result<((u8, u16), (u32,)), ()> = tuple_split::split_result_at::<2, _, _>(Ok((1, 2, 3)));
+        assert_eq!(SPLIT, Ok(((1, 2), (3,))));
+    }
+
+    #[cfg(feature = "rejoin")]
+    #[test]
+    fn test_rejoin_checked()
+    {
+        let t = (1u8, 2u16, 3u32, 4u64);
+
+        let (l, r) = tuple_split::split_tuple_at::<1, _>(t);
+        assert_eq!(tuple_split::rejoin_checked::<(u8, u16, u32, u64), _, _>(l, r), t);
+
+        let (l, r) = tuple_split::split_tuple_at::<4, _>(t);
+        assert_eq!(tuple_split::rejoin_checked::<(u8, u16, u32, u64), _, _>(l, r), t);
+
+        assert_eq!(tuple_split::rejoin_checked::<(), _, _>((), ()), ());
+    }
+
+    #[test]
+    fn test_split_array_of_tuples()
+    {
+        let a = [(1u8, 1.0f32), (2, 2.0), (3, 3.0)];
+
+        let (l, r) = tuple_split::split_array_of_tuples_at::<1, _, 3>(a);
+        assert_eq!(l, [(1,), (2,), (3,)]);
+        assert_eq!(r, [(1.0,), (2.0,), (3.0,)]);
+
+        for (i, t) in a.into_iter().enumerate()
+        {
+            assert_eq!(t, tupleops::concat_tuples(l[i], r[i]));
+        }
+
+        let (l, r) = tuple_split::split_array_of_tuples_at::<0, (u8, f32), 0>([]);
+        assert_eq!(l, []);
+        assert_eq!(r, []);
+    }
+
+    #[test]
+    fn test_split_at_type_len()
+    {
+        let t = (1u8, 2u16, 3u32);
+
+        assert_eq!(tuple_split::split_at_type_len::<(), _>(t), ((), t));
+        assert_eq!(tuple_split::split_at_type_len::<(f64,), _>(t), ((1,), (2, 3)));
+        assert_eq!(tuple_split::split_at_type_len::<(&str, char, bool), _>(t), (t, ()));
+    }
+
+    #[test]
+    fn test_split_at2()
+    {
+        let t = (1u8, 2u16, 3u32, 4u64);
+
+        assert_eq!(tuple_split::split_tuple_at2::<0, 4, _>(t), ((), t, ()));
+        assert_eq!(tuple_split::split_tuple_at2::<1, 2, _>(t), ((1,), (2,), (3, 4)));
+        assert_eq!(tuple_split::split_tuple_at2::<2, 4, _>(t), ((1, 2), (3, 4), ()));
+    }
+
+    #[test]
+    fn test_reverse_split()
+    {
+        let t = (1u8, 2u16, 3u32, 4u64);
+
+        assert_eq!(tuple_split::reverse_tuple(t), (4, 3, 2, 1));
+        assert_eq!(tuple_split::reverse_tuple(()), ());
+
+        assert_eq!(tuple_split::reverse_split::<1, _>(t), ((4,), (3, 2, 1)));
+        assert_eq!(tuple_split::reverse_split::<0, _>(t), ((), (4, 3, 2, 1)));
+
+        // Reversing within the halves keeps them at their own ends of the tuple.
+        let (l, r) = tuple_split::split_tuple_at::<1, _>(t);
+        assert_eq!((tuple_split::reverse_tuple(l), tuple_split::reverse_tuple(r)), ((1,), (4, 3, 2)));
+    }
+
+    #[test]
+    fn test_split_and_pad_left()
+    {
+        let t = (1u8, 2u8, 3u8, 4u8);
+
+        assert_eq!(tuple_split::split_and_pad_left::<1, 3, _, _>(t), ((1, 0, 0), (2, 3, 4)));
+        assert_eq!(tuple_split::split_and_pad_left::<0, 2, _, _>(t), ((0, 0), t));
+        assert_eq!(tuple_split::split_and_pad_left::<3, 3, _, _>(t), ((1, 2, 3), (4,)));
+        assert_eq!(tuple_split::split_and_pad_left::<3, 2, _, _>(t), ((1, 2, 3), (4,)));
+    }
+
+    #[test]
+    fn test_split_info()
+    {
+        let t = (1u8, 2u16, 3u32, 4u64);
+
+        let info = tuple_split::split_tuple_at_info::<1, _>(t);
+        assert_eq!(info, tuple_split::SplitInfo { left: (1,), right: (2, 3, 4) });
+        assert_eq!(t, tupleops::concat_tuples(info.left, info.right));
+
+        type Info = tuple_split::SplitInfo<(u8, u16, u32, u64), ()>;
+        let info: Info = tuple_split::split_tuple_at_info::<4, _>(t);
+        assert_eq!(info.right, ());
+        assert_eq!((Info::LEFT_LEN, Info::RIGHT_LEN, Info::TOTAL_LEN), (4, 0, 4));
+        assert_eq!(tuple_split::SplitInfo::<(u8,), (u16, u32, u64)>::TOTAL_LEN, 4);
+    }
+
+    #[cfg(feature = "8")]
+    #[test]
+    fn test_chunks()
+    {
+        let t = (1u8, 2u16, 3u32, 4u64, 5u128, 6i8);
+
+        assert_eq!(tuple_split::chunks_tuple::<2, _>(t), ((1, 2), (3, 4), (5, 6)));
+        assert_eq!(tuple_split::chunks_tuple::<3, _>(t), ((1, 2, 3), (4, 5, 6)));
+        assert_eq!(tuple_split::chunks_tuple::<4, _>(t), ((1, 2, 3, 4), (5, 6)));
+        assert_eq!(tuple_split::chunks_tuple::<5, _>(t), ((1, 2, 3, 4, 5), (6,)));
+        assert_eq!(tuple_split::chunks_tuple::<6, _>(t), (t,));
+        assert_eq!(tuple_split::chunks_tuple::<7, _>(t), (t,));
+        assert_eq!(tuple_split::chunks_tuple::<1, _>((1u8, 2u16)), ((1,), (2,)));
+        assert_eq!(tuple_split::chunks_tuple::<2, _>(()), ());
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn test_split_pinned_futures()
+    {
+        use core::{
+            future::Future,
+            marker::PhantomPinned,
+            pin::{pin, Pin},
+            task::{Context, Poll, Waker}
+        };
+
+        /// A future which is ready after being polled a given number of times, and which may not be moved once pinned.
+        struct Countdown
+        {
+            polls_left: usize,
+            _pinned: PhantomPinned
+        }
+
+        impl Countdown
+        {
+            fn new(polls_left: usize) -> Self
+            {
+                Self {
+                    polls_left,
+                    _pinned: PhantomPinned
+                }
+            }
+        }
+
+        impl Future for Countdown
+        {
+            type Output = ();
+
+            fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()>
+            {
+                // SAFETY: Only the counter is touched, nothing is moved.
+                let this = unsafe { self.get_unchecked_mut() };
+                if this.polls_left == 0
+                {
+                    return Poll::Ready(());
+                }
+                this.polls_left -= 1;
+                Poll::Pending
+            }
+        }
+
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut futures = pin!((Countdown::new(0), Countdown::new(1), Countdown::new(2)));
+
+        let ((a,), (b, c)) = tuple_split::split_pinned_futures_at::<1, _>(futures.as_mut());
+        assert_eq!(a.poll(&mut cx), Poll::Ready(()));
+        assert_eq!(b.poll(&mut cx), Poll::Pending);
+        assert_eq!(c.poll(&mut cx), Poll::Pending);
+
+        let ((_, b), (c,)) = tuple_split::split_pinned_futures_at::<2, _>(futures.as_mut());
+        assert_eq!(b.poll(&mut cx), Poll::Ready(()));
+        assert_eq!(c.poll(&mut cx), Poll::Pending);
+
+        let ((), (a, b, c)) = tuple_split::split_pinned_futures_at::<0, _>(futures.as_mut());
+        assert_eq!(a.poll(&mut cx), Poll::Ready(()));
+        assert_eq!(b.poll(&mut cx), Poll::Ready(()));
+        assert_eq!(c.poll(&mut cx), Poll::Ready(()));
+    }
+
+    #[test]
+    fn test_split_at_const()
+    {
+        use tuple_split::ConstUsize;
+
+        struct Index<const I: usize>;
+
+        impl<const I: usize> ConstUsize for Index<I>
+        {
+            const VALUE: usize = I;
+        }
+
+        struct Sum<A, B>(A, B);
+
+        impl<A, B> ConstUsize for Sum<A, B>
+        where
+            A: ConstUsize,
+            B: ConstUsize
+        {
+            const VALUE: usize = A::VALUE + B::VALUE;
+        }
+
+        let t = (1u8, 2u16, 3u32, 4u64);
+
+        assert_eq!(tuple_split::split_tuple_at_const::<Index<0>, _>(t), ((), t));
+        assert_eq!(tuple_split::split_tuple_at_const::<Index<1>, _>(t), ((1,), (2, 3, 4)));
+        assert_eq!(tuple_split::split_tuple_at_const::<Sum<Index<1>, Index<2>>, _>(t), ((1, 2, 3), (4,)));
+    }
+
+    #[test]
+    fn test_split_table()
+    {
+        tuple_split::split_table! {
+            type Packet = (u8, u16, u32, u64);
+
+            const HEADER: usize = 1 => fn header_split;
+            const BODY: usize = 3 => fn body_split;
+        }
+
+        let packet: Packet = (1, 2, 3, 4);
+
+        assert_eq!((HEADER, BODY), (1, 3));
+        assert_eq!(header_split(packet), ((1,), (2, 3, 4)));
+        assert_eq!(body_split(packet), ((1, 2, 3), (4,)));
+    }
+
+    #[test]
+    fn test_split_cow()
+    {
+        use tuple_split::SplitCow;
+
+        let t = (1u8, 2u16, 3u32);
+
+        let (l, r) = tuple_split::split_tuple_at_cow::<1, _>(SplitCow::Owned(t));
+        assert!(l.is_owned() && r.is_owned());
+        assert_eq!(l, SplitCow::Owned((1,)));
+        assert_eq!(r, SplitCow::Owned((2, 3)));
+
+        let (l, r) = tuple_split::split_tuple_at_cow::<1, _>(SplitCow::borrowed(&t));
+        assert!(l.is_borrowed() && r.is_borrowed());
+        assert_eq!(l, SplitCow::Borrowed((&t.0,)));
+        assert_eq!(r, SplitCow::Borrowed((&t.1, &t.2)));
+
+        let (l, r) = tuple_split::split_tuple_at_cow::<0, _>(SplitCow::borrowed(&t));
+        assert_eq!(l, SplitCow::Borrowed(()));
+        assert_eq!(r, SplitCow::Borrowed((&1, &2, &3)));
+    }
+
+    #[test]
+    fn test_drop_order()
+    {
+        use core::cell::RefCell;
+
+        struct Log
+        {
+            dropped: [u8; 4],
+            len: usize
+        }
+
+        struct Noisy<'a>(u8, &'a RefCell<Log>);
+
+        impl Drop for Noisy<'_>
+        {
+            fn drop(&mut self)
+            {
+                let mut log = self.1.borrow_mut();
+                let len = log.len;
+                log.dropped[len] = self.0;
+                log.len += 1;
+            }
+        }
+
+        let log = RefCell::new(Log { dropped: [0; 4], len: 0 });
+        let t = (Noisy(1, &log), Noisy(2, &log), Noisy(3, &log), Noisy(4, &log));
+
+        let (l, r) = tuple_split::split_tuple_at::<2, _>(t);
+        assert_eq!(log.borrow().len, 0);
+
+        drop(l);
+        assert_eq!(log.borrow().dropped[..log.borrow().len], [1, 2]);
+
+        drop(r);
+        assert_eq!(log.borrow().dropped, [1, 2, 3, 4]);
+
+        let log = RefCell::new(Log { dropped: [0; 4], len: 0 });
+        let t = (Noisy(1, &log), Noisy(2, &log), Noisy(3, &log), Noisy(4, &log));
+
+        let (_, (c, d)) = tuple_split::split_tuple_at::<2, _>(t);
+        assert_eq!(log.borrow().dropped[..log.borrow().len], [1, 2]);
+
+        drop((c, d));
+        assert_eq!(log.borrow().dropped, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_split_whole()
+    {
+        type Rest = (u32, u64);
+
+        let t = (1u8, 2u16, 3u32, 4u64);
+
+        let (l, r): ((u8, u16), Rest) = tuple_split::split_whole::<(u8, u16), _, _>(t);
+        assert_eq!(l, (1, 2));
+        assert_eq!(r, (3, 4));
+
+        let (l, r) = tuple_split::split_whole::<(), _, _>(t);
+        assert_eq!((l, r), ((), t));
+    }
+
+    #[test]
+    fn test_split_map_rebuild()
+    {
+        use core::marker::Tuple;
+
+        use tuple_split::{SplitVisitor, TupleReverse};
+
+        struct ReverseLeft
+        {
+            visits: usize
+        }
+
+        impl<L, R> SplitVisitor<L, R> for ReverseLeft
+        where
+            L: TupleReverse,
+            R: Tuple
+        {
+            type Left = L::Reversed;
+            type Right = R;
+
+            fn transform_left(&mut self, left: L) -> Self::Left
+            {
+                self.visits += 1;
+                left.reverse_tuple()
+            }
+
+            fn transform_right(&mut self, right: R) -> Self::Right
+            {
+                self.visits += 1;
+                right
+            }
+        }
+
+        let t = (1u8, 2u16, 3u32, 4u64);
+
+        assert_eq!(tuple_split::split_map_rebuild::<3, _, _>(t, ReverseLeft { visits: 0 }), (3, 2, 1, 4));
+        assert_eq!(tuple_split::split_map_rebuild::<0, _, _>(t, ReverseLeft { visits: 0 }), t);
+        assert_eq!(tuple_split::split_map_rebuild::<4, _, _>(t, ReverseLeft { visits: 0 }), (4, 3, 2, 1));
+
+        let mut visitor = ReverseLeft { visits: 0 };
+        assert_eq!(tuple_split::split_map_rebuild::<2, _, _>(t, &mut visitor), (2, 1, 3, 4));
+        assert_eq!(visitor.visits, 2);
+    }
+
+    #[test]
+    fn test_split_and_map()
+    {
+        use tupleops::TupleMapper;
+
+        struct Enumerate(usize);
+
+        impl TupleMapper for Enumerate
+        {
+            type MapElem<Elem> = (usize, Elem);
+
+            fn map_elem<Elem>(&mut self, elem: Elem) -> Self::MapElem<Elem>
+            {
+                let index = self.0;
+                self.0 += 1;
+                (index, elem)
+            }
+        }
+
+        let t = (1u8, 2u16, 3u32);
+
+        assert_eq!(tuple_split::split_and_map_left::<2, _, _>(t, Enumerate(0)), (((0, 1), (1, 2)), (3,)));
+        assert_eq!(tuple_split::split_and_map_right::<1, _, _>(t, Enumerate(1)), ((1,), ((1, 2), (2, 3))));
+        assert_eq!(tuple_split::split_and_map_left::<0, _, _>(t, Enumerate(0)), ((), t));
+
+        let mut calls = 0;
+        let mut count = |a: u8, b: u16| {
+            calls += 1;
+            a as u32 + b as u32
+        };
+        assert_eq!(tuple_split::split_and_apply_left::<2, _, _>(t, &mut count), (3, (3,)));
+        assert_eq!(calls, 1);
+
+        let mut bump = || calls += 1;
+        assert_eq!(tuple_split::split_and_apply_left::<0, _, _>(t, &mut bump), ((), t));
+        assert_eq!(calls, 1);
+    }
+
+    #[cfg(all(feature = "nested", feature = "16", not(feature = "dont_hurt_yourself_by_using_all_features")))]
+    #[test]
+    fn test_split_nested()
+    {
+        use tuple_split::TupleNested;
+
+        type Chunk = (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8);
+
+        let t: (Chunk, Chunk, Chunk, Chunk) = (
+            (0, 1, 2, 3, 4, 5, 6, 7, 8, 9),
+            (10, 11, 12, 13, 14, 15, 16, 17, 18, 19),
+            (20, 21, 22, 23, 24, 25, 26, 27, 28, 29),
+            (30, 31, 32, 33, 34, 35, 36, 37, 38, 39)
+        );
+        assert_eq!(<(Chunk, Chunk, Chunk, Chunk)>::FLAT_LEN, 40);
+
+        let (l, r) = tuple_split::split_nested_at::<0, _>(t);
+        assert_eq!(l, ((),));
+        assert_eq!(r, t);
+
+        let (l, r) = tuple_split::split_nested_at::<5, _>(t);
+        assert_eq!(l, ((0, 1, 2, 3, 4),));
+        assert_eq!(r, ((5, 6, 7, 8, 9), t.1, t.2, t.3));
+
+        let (l, r) = tuple_split::split_nested_at::<10, _>(t);
+        assert_eq!(l, (t.0,));
+        assert_eq!(r, ((), t.1, t.2, t.3));
+
+        let (l, r) = tuple_split::split_nested_at::<25, _>(t);
+        assert_eq!(l, (t.0, t.1, (20, 21, 22, 23, 24)));
+        assert_eq!(r, ((25, 26, 27, 28, 29), t.3));
+        assert_eq!(<(Chunk, Chunk, (u8, u8, u8, u8, u8))>::FLAT_LEN + <((u8, u8, u8, u8, u8), Chunk)>::FLAT_LEN, 40);
+
+        let (l, r) = tuple_split::split_nested_at::<40, _>(t);
+        assert_eq!(l, t);
+        assert_eq!(r, ((),));
+    }
+
+    #[test]
+    fn test_accumulator()
+    {
+        use tuple_split::TupleAccumulator;
+
+        let mut acc = TupleAccumulator::<(u8, &'static str, f32)>::new();
+        assert!(acc.is_empty());
+
+        acc.push_back(1u8).unwrap();
+        acc.push_back("two").unwrap();
+        assert_eq!(acc.len(), 2);
+        assert!(!acc.is_complete());
+
+        assert_eq!(acc.push_back(3u8), Err(3));
+        assert_eq!(acc.len(), 2);
+
+        let mut acc = acc.finish_split::<1>().unwrap_err();
+        acc.push_back(3.0f32).unwrap();
+        assert!(acc.is_complete());
+        assert_eq!(acc.push_back(4.0f32), Err(4.0));
+
+        assert_eq!(acc.finish_split::<1>(), Ok(((1,), ("two", 3.0))));
+
+        let acc = TupleAccumulator::<()>::new();
+        assert!(acc.is_complete());
+        assert_eq!(acc.finish(), Ok(()));
+    }
+
+    #[test]
+    fn test_split_at_iter()
+    {
+        let t = (1u8, 2u8, 3u8, 4u8);
+
+        let (l, r) = tuple_split::split_at_iter::<1, _, _>(t);
+        assert!(l.eq([1]));
+        assert!(r.eq([2, 3, 4]));
+
+        let (l, r) = tuple_split::split_at_iter::<4, _, _>(t);
+        assert!(l.rev().eq([4, 3, 2, 1]));
+        assert_eq!(r.count(), 0);
+
+        let (l, r) = tuple_split::split_at_iter::<2, _, _>(("a", "b", "c"));
+        assert!(l.chain(r).eq(["a", "b", "c"]));
+    }
+
+    #[test]
+    fn test_split_at_typed()
+    {
+        let t = (1u8, 2u16, "three");
+
+        let (l, r) = tuple_split::split_at_typed::<1, (u8,), (u16, &str), _>(t);
+        assert_eq!(l, (1,));
+        assert_eq!(r, (2, "three"));
+
+        let (l, r) = tuple_split::at::split_at_typed::<3, (u8, u16, &str), (), _>(t);
+        assert_eq!(l, t);
+        assert_eq!(r, ());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_split_erased()
+    {
+        extern crate std;
+
+        use std::format;
+
+        let (l, r) = tuple_split::split_erased_at((1u8, 2.0f32, "three"), 1).unwrap();
+        assert_eq!((l.len(), r.len()), (1, 2));
+        assert_eq!(format!("{l:?}"), "(1,)");
+        assert_eq!(format!("{r:?}"), "(2.0, \"three\")");
+
+        let (l, r) = tuple_split::split_erased_at((1u8, 2.0f32, "three"), 3).unwrap();
+        assert_eq!((l.len(), r.len()), (3, 0));
+        assert!(r.is_empty());
+
+        assert!(tuple_split::split_erased_at((1u8, 2.0f32, "three"), 4).is_none());
+
+        fn left_len<T>(tuple: T, index: usize) -> Option<usize>
+        where
+            T: tuple_split::TupleErase
+        {
+            tuple_split::split_erased_at(tuple, index).map(|(l, _)| l.len())
+        }
+        assert_eq!(left_len((1u8, "two"), 1), Some(1));
+    }
+
+    #[test]
+    fn test_remove_at()
+    {
+        let t = (1u8, 2u16, "three", 4.0f32);
+
+        let (rest, removed) = tuple_split::remove_many::<{ &[0, 2] }, _>(t);
+        assert_eq!(rest, (2, 4.0));
+        assert_eq!(removed, (1, "three"));
+
+        let (rest, removed) = tuple_split::remove_many::<{ &[1, 2, 3] }, _>(t);
+        assert_eq!(rest, (1,));
+        assert_eq!(removed, (2, "three", 4.0));
+
+        let (rest, removed) = tuple_split::remove_many::<{ &[] }, _>(t);
+        assert_eq!(rest, t);
+        assert_eq!(removed, ());
+
+        let (rest, removed) = tuple_split::remove_many::<{ &[0, 1, 2, 3] }, _>(t);
+        assert_eq!(rest, ());
+        assert_eq!(removed, t);
+
+        let (rest, removed) = tuple_split::remove_at::<3, _>(t);
+        assert_eq!(rest, (1, 2, "three"));
+        assert_eq!(removed, 4.0);
+    }
+
+    #[test]
+    fn test_zip_split3()
+    {
+        let a = (1u8, 2u8, 3u8);
+        let b = ("a", "b", "c");
+        let c = (1.0f32, 2.0f32, 3.0f32);
+
+        let (l, r) = tuple_split::zip_split3::<1, _, _, _>(a, b, c);
+        assert_eq!(l, ((1,), ("a",), (1.0,)));
+        assert_eq!(r, ((2, 3), ("b", "c"), (2.0, 3.0)));
+
+        let (l, r) = tuple_split::zip_split3::<3, _, _, _>(a, b, c);
+        assert_eq!(l, (a, b, c));
+        assert_eq!(r, ((), (), ()));
+
+        let (l, r) = tuple_split::zip_split::<2, _, _>(a, b);
+        assert_eq!(l, ((1, 2), ("a", "b")));
+        assert_eq!(r, ((3,), ("c",)));
+    }
+
+    #[test]
+    fn test_split_type_at()
+    {
+        use tuple_split::{SplitTypeAt, TupleLen};
+
+        type T = (u8, f32, &'static str);
+
+        const LEFT: <T as SplitTypeAt<2>>::Left = (1, 1.0);
+        const LEFT_LEN: usize = <<T as SplitTypeAt<2>>::Left as TupleLen>::LEN;
+        const RIGHT_LEN: usize = <<T as SplitTypeAt<2>>::Right as TupleLen>::LEN;
+
+        assert_eq!(LEFT, (1, 1.0));
+        assert_eq!((LEFT_LEN, RIGHT_LEN), (2, 1));
 
-        assert_eq!(r1, r2);
-        assert_eq!(r2, r3);
+        let (l, r): (<T as SplitTypeAt<2>>::Left, <T as SplitTypeAt<2>>::Right) = tuple_split::split_tuple_at::<2, _>((1u8, 1.0f32, "test"));
+        assert_eq!(l, LEFT);
+        assert_eq!(r, ("test",));
+    }
 
-        assert_eq!(t, tupleops::concat_tuples(l1, r1));
-        assert_eq!(t, tupleops::concat_tuples(l2, r2));
-        assert_eq!(t, tupleops::concat_tuples(l3, r3));
+    #[test]
+    fn test_split_at_unwrap_single()
+    {
+        let t = (1u8, 2u16, "three");
+
+        let (l, r) = tuple_split::split_at_unwrap_single::<1, _>(t);
+        assert_eq!(l, 1u8);
+        assert_eq!(r, (2u16, "three"));
+
+        let (l, r) = tuple_split::split_at_unwrap_single::<2, _>(t);
+        assert_eq!(l, (1u8, 2u16));
+        assert_eq!(r, "three");
+
+        let (l, r) = tuple_split::split_at_unwrap_single::<0, _>((1u8,));
+        assert_eq!(l, ());
+        assert_eq!(r, 1u8);
+    }
+
+    #[test]
+    fn test_split_error()
+    {
+        use core::fmt::Write;
+
+        use tuple_split::SplitError;
+
+        struct Buf([u8; 64], usize);
+
+        impl Write for Buf
+        {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result
+            {
+                let end = self.1 + s.len();
+                self.0.get_mut(self.1..end).ok_or(core::fmt::Error)?.copy_from_slice(s.as_bytes());
+                self.1 = end;
+                Ok(())
+            }
+        }
+
+        let error = tuple_split::try_split_at_iter((1, 2, 3), 5).err().unwrap();
+        assert_eq!(error, SplitError { index: 5, len: 3 });
+
+        let mut buf = Buf([0; 64], 0);
+        write!(buf, "{error}").unwrap();
+        assert_eq!(core::str::from_utf8(&buf.0[..buf.1]), Ok("cannot split a tuple of length 3 at index 5"));
+
+        let error: &dyn core::error::Error = &error;
+        assert!(error.source().is_none());
+
+        let (l, r) = tuple_split::try_split_at_iter((1, 2, 3), 3).unwrap();
+        assert!(l.eq([1, 2, 3]));
+        assert_eq!(r.count(), 0);
+    }
+
+    #[cfg(feature = "array_split")]
+    #[test]
+    fn test_split_array()
+    {
+        let (l, r) = tuple_split::split_tuple_at::<1, _>((1, 2, 3));
+        assert_eq!((l, r), ((1,), (2, 3)));
+
+        let (l, r) = tuple_split::split_tuple_at::<1, _>([1, 2, 3]);
+        assert_eq!((l, r), ([1], [2, 3]));
+
+        let (l, r) = tuple_split::split_tuple_at::<3, _>([1, 2, 3]);
+        assert_eq!((l, r), ([1, 2, 3], []));
+
+        const SPLIT: ([u8; 2], [u8; 1]) = tuple_split::split_tuple_at::<2, _>([1, 2, 3]);
+        assert_eq!(SPLIT, ([1, 2], [3]));
+
+        let log = core::cell::RefCell::new([0; 3]);
+        let count = core::cell::Cell::new(0);
+
+        struct Logged<'a>(u8, &'a core::cell::RefCell<[u8; 3]>, &'a core::cell::Cell<usize>);
+
+        impl Drop for Logged<'_>
+        {
+            fn drop(&mut self)
+            {
+                self.1.borrow_mut()[self.2.get()] = self.0;
+                self.2.set(self.2.get() + 1);
+            }
+        }
+
+        let (l, r) = tuple_split::split_tuple_at::<1, _>([Logged(1, &log, &count), Logged(2, &log, &count), Logged(3, &log, &count)]);
+        drop(r);
+        drop(l);
+        assert_eq!(*log.borrow(), [2, 3, 1]);
+    }
+
+    #[test]
+    fn test_split_at_first()
+    {
+        use tuple_split::PositionPredicate;
+
+        struct AtLeastTwo;
+
+        impl<T, const INDEX: usize> PositionPredicate<T, INDEX> for AtLeastTwo
+        {
+            const MATCHES: bool = INDEX >= 2;
+        }
+
+        struct IsStr;
+
+        impl<const INDEX: usize> PositionPredicate<u8, INDEX> for IsStr
+        {
+            const MATCHES: bool = false;
+        }
+        impl<const INDEX: usize> PositionPredicate<f32, INDEX> for IsStr
+        {
+            const MATCHES: bool = false;
+        }
+        impl<const INDEX: usize> PositionPredicate<&str, INDEX> for IsStr
+        {
+            const MATCHES: bool = true;
+        }
+
+        let t = (1u8, 2.0f32, "three", 4u8);
+
+        let (l, r) = tuple_split::split_at_first::<AtLeastTwo, _>(t);
+        assert_eq!(l, (1, 2.0));
+        assert_eq!(r, ("three", 4));
+
+        let (l, r) = tuple_split::split_at_first::<IsStr, _>((1u8, "two", 3.0f32, "four"));
+        assert_eq!(l, (1,));
+        assert_eq!(r, ("two", 3.0, "four"));
+
+        let (l, r) = tuple_split::split_at_first::<IsStr, _>((1u8, 2.0f32));
+        assert_eq!(l, (1, 2.0));
+        assert_eq!(r, ());
+
+        let (l, r) = tuple_split::split_at_first::<AtLeastTwo, _>(());
+        assert_eq!((l, r), ((), ()));
+    }
+
+    #[cfg(feature = "refs")]
+    #[test]
+    fn test_tupleops_refs()
+    {
+        use tupleops::{RefMutTuple, RefTuple};
+
+        let mut t = (1u8, 2.0f32, "three");
+
+        let (l, r): (RefTuple<'_, (u8,)>, RefTuple<'_, (f32, &str)>) = tuple_split::split_tuple_at_ref::<1, _>(&t);
+        assert_eq!(tupleops::concat_tuples(l, r), tupleops::ref_tuple(&t));
+
+        let (l, r): (RefMutTuple<'_, (u8,)>, RefMutTuple<'_, (f32, &str)>) = tuple_split::split_tuple_at_mut::<1, _>(&mut t);
+        *l.0 += 1;
+        *r.0 *= 2.0;
+        assert_eq!(t, (2, 4.0, "three"));
+    }
+
+    #[test]
+    fn test_parts()
+    {
+        use tuple_split::Parts;
+
+        let t = (1u8, 2.0f32, "three");
+
+        let parts = tuple_split::split_tuple_at_parts::<1, _>(t);
+        assert_eq!(parts, Parts::from_parts((1,), (2.0, "three")));
+        assert_eq!(parts.into_parts(), tuple_split::split_tuple_at::<1, _>(t));
+        assert_eq!(parts.rejoin(), t);
+
+        let parts = tuple_split::split_tuple_into_left_parts::<(u8, f32), _>(t);
+        assert_eq!(parts.right, ("three",));
+        let parts = tuple_split::split_tuple_into_right_parts::<(f32, &str), _>(t);
+        assert_eq!(parts.left, (1,));
+
+        let parts: Parts<_, _> = tuple_split::split_halves((1, 2, 3, 4)).into();
+        let (l, r): ((i32, i32), (i32, i32)) = parts.into();
+        assert_eq!((l, r), ((1, 2), (3, 4)));
+        assert_eq!(Parts::from_parts(l, r).rejoin(), (1, 2, 3, 4));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_parts_serde()
+    {
+        use serde_test::Token;
+
+        let parts = tuple_split::split_tuple_at_parts::<1, _>((1u8, 2u16, "three"));
+
+        serde_test::assert_tokens(
+            &parts,
+            &[
+                Token::Tuple { len: 2 },
+                Token::Tuple { len: 1 },
+                Token::U8(1),
+                Token::TupleEnd,
+                Token::Tuple { len: 2 },
+                Token::U16(2),
+                Token::BorrowedStr("three"),
+                Token::TupleEnd,
+                Token::TupleEnd
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tuple_shape()
+    {
+        use tuple_split::{SplitTypeAt, TupleShape};
+
+        type T = (u8, [u16; 3], u32, ());
+
+        const SHAPE: &[(usize, usize)] = tuple_split::tuple_shape::<T>();
+        assert_eq!(SHAPE, &[(1, 1), (6, 2), (4, 4), (0, 1)]);
+        assert_eq!(tuple_split::tuple_shape::<()>(), &[]);
+
+        let (left, right) = SHAPE.split_at(2);
+        assert_eq!(<<T as SplitTypeAt<2>>::Left>::SHAPE, left);
+        assert_eq!(<<T as SplitTypeAt<2>>::Right>::SHAPE, right);
+    }
+
+    #[test]
+    fn test_split_range()
+    {
+        use tuple_split::SplitRange;
+
+        const RANGE: SplitRange<1, 3> = SplitRange::new();
+
+        let (l, m, r) = tuple_split::split_range((1u8, 2u16, 3u32, 4u64), RANGE);
+        assert_eq!((l, m, r), ((1,), (2, 3), (4,)));
+
+        let (l, m, r) = tuple_split::split_range(("a", 'b', 3.0f32, true), RANGE);
+        assert_eq!((l, m, r), (("a",), ('b', 3.0), (true,)));
+
+        const EMPTY: SplitRange<2, 2> = SplitRange::new();
+
+        let (l, m, r) = tuple_split::split_range((1u8, 2u16, 3u32, 4u64), EMPTY);
+        assert_eq!((l, m, r), ((1, 2), (), (3, 4)));
+
+        let (l, m, r) = tuple_split::split_range((), SplitRange::<0, 0>::default());
+        assert_eq!((l, m, r), ((), (), ()));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_debug_split()
+    {
+        use alloc::string::String;
+
+        let mut out = String::new();
+
+        let (l, r) = tuple_split::debug_split::<1, _>((1u8, 'b', "three"), &mut out);
+        assert_eq!((l, r), ((1,), ('b', "three")));
+
+        let (l, r) = tuple_split::debug_split::<0, _>((), &mut out);
+        assert_eq!((l, r), ((), ()));
+
+        assert_eq!(out, "left: (1,), right: ('b', \"three\")\nleft: (), right: ()\n");
+    }
+
+    #[test]
+    fn test_split_reverse_half()
+    {
+        let t = (1u8, 2u16, 3u32, 4u64);
+
+        let (l, r) = tuple_split::split_tuple_at::<2, _>(t);
+        assert_eq!(tuple_split::split_reverse_right::<2, _>(t), (l, tuple_split::reverse_tuple(r)));
+        assert_eq!(tuple_split::split_reverse_left::<2, _>(t), (tuple_split::reverse_tuple(l), r));
+
+        assert_eq!(tuple_split::split_reverse_right::<0, _>(t), ((), (4, 3, 2, 1)));
+        assert_eq!(tuple_split::split_reverse_left::<4, _>(t), ((4, 3, 2, 1), ()));
+    }
+
+    #[test]
+    fn test_collect_tuple()
+    {
+        assert_eq!(tuple_split::collect_tuple::<3, _>(1..4), Some((1, 2, 3)));
+        assert_eq!(tuple_split::collect_tuple::<3, _>(1..3), None);
+        assert_eq!(tuple_split::collect_tuple::<0, _>(1..3), Some(()));
+
+        let mut iter = 1..10;
+        assert_eq!(tuple_split::collect_tuple::<2, _>(&mut iter), Some((1, 2)));
+        assert_eq!(iter.next(), Some(3));
+
+        let (l, r) = tuple_split::split_at_iter::<1, _, _>((1, 2, 3));
+        assert_eq!(tuple_split::collect_tuple::<3, _>(r.chain(l)), Some((2, 3, 1)));
+    }
+
+    #[cfg(feature = "refs")]
+    #[test]
+    fn test_swap_and_split_mut()
+    {
+        let mut t = (1u8, 2u16, 3u32, 4u64);
+
+        let ((c, d), (a, b)) = tuple_split::swap_and_split_mut::<2, _>(&mut t);
+        *d += *c as u64;
+        *c += *b as u32;
+        *b += *a as u16;
+        *a = 0;
+        assert_eq!(t, (0, 3, 5, 7));
+
+        let ((), (a, b, c, d)) = tuple_split::swap_and_split_mut::<4, _>(&mut t);
+        *a = *d as u8;
+        *b = 0;
+        *c = 0;
+        assert_eq!(t, (7, 0, 0, 7));
+    }
+
+    #[cfg(feature = "refs")]
+    #[test]
+    fn test_split_peek_commit()
+    {
+        fn take_if_small<T>(t: T) -> Result<(T::Left, T::Right), T>
+        where
+            T: tuple_split::TupleSplitAtRef<1, Left = (u8,)>
+        {
+            let ((first,), _) = tuple_split::split_peek::<1, _>(&t);
+            if *first < 10
+            {
+                Ok(tuple_split::split_commit::<1, _>(t))
+            }
+            else
+            {
+                Err(t)
+            }
+        }
+
+        assert_eq!(take_if_small((1u8, "small")), Ok(((1,), ("small",))));
+        assert_eq!(take_if_small((100u8, "big")), Err((100, "big")));
+    }
+
+    #[cfg(feature = "refs")]
+    #[test]
+    fn test_split_ref_with_proof()
+    {
+        use tuple_split::{ConstUsize, SplitIndex};
+
+        fn inspect<T>(t: &T) -> SplitIndex<1>
+        where
+            T: tuple_split::TupleSplitAtRef<1, Left = (u8,)>
+        {
+            let ((first,), _, index) = tuple_split::split_ref_with_proof::<1, _>(t);
+            assert_eq!(*first, 1);
+            index
+        }
+
+        let t = (1u8, 2u16, "three");
+
+        let index = inspect(&t);
+        assert_eq!(SplitIndex::<1>::VALUE, 1);
+
+        let (l, r) = index.split_tuple_at(t);
+        assert_eq!(l, (1,));
+        assert_eq!(r, (2, "three"));
+    }
+
+    #[test]
+    fn test_split_tagged()
+    {
+        use core::marker::PhantomData;
+
+        #[derive(Debug, PartialEq)]
+        struct Tag;
+
+        type P = PhantomData<Tag>;
+
+        let t = (1u8, 2u16, 3u32, PhantomData::<Tag>);
+
+        let (l, r): ((u8, P), (u16, u32, P)) = tuple_split::split_tagged_at::<1, Tag, _>(t);
+        assert_eq!(l, (1, PhantomData));
+        assert_eq!(r, (2, 3, PhantomData));
+
+        let (l, r): ((P,), (u8, u16, u32, P)) = tuple_split::split_tagged_at::<0, Tag, _>(t);
+        assert_eq!(l, (PhantomData,));
+        assert_eq!(r, t);
+
+        let (l, r): ((u8, u16, u32, P), (P,)) = tuple_split::split_tagged_at::<3, Tag, _>(t);
+        assert_eq!(l, t);
+        assert_eq!(r, (PhantomData,));
+    }
+
+    #[test]
+    fn test_split_cyclic()
+    {
+        let t = (1u8, 2u16, 3u32, 4u64);
+
+        assert_eq!(tuple_split::split_cyclic::<1, 2, _>(t), ((2, 3), (4, 1)));
+        assert_eq!(tuple_split::split_cyclic::<3, 2, _>(t), ((4, 1), (2, 3)));
+        assert_eq!(tuple_split::split_cyclic::<2, 4, _>(t), ((3, 4, 1, 2), ()));
+        assert_eq!(tuple_split::split_cyclic::<4, 0, _>(t), ((), t));
+    }
+
+    /// Compile-time checks of the elements of split halves. See [VerifySplit](crate::private::VerifySplit).
+    mod verify
+    {
+        use crate::{
+            private::{packs_tightly, VerifySplit},
+            TupleShape
+        };
+
+        #[test]
+        fn test_packs_tightly()
+        {
+            assert!(packs_tightly(<()>::SHAPE));
+            assert!(packs_tightly(<(u8, [u8; 3])>::SHAPE));
+            assert!(packs_tightly(<(u32, [u16; 2], (), char)>::SHAPE));
+            assert!(packs_tightly(<(u64, (u32, u32))>::SHAPE));
+            assert!(!packs_tightly(<(u8, u16)>::SHAPE));
+            assert!(!packs_tightly(<(u32, [u8; 3])>::SHAPE));
+        }
+
+        #[test]
+        fn test_verify_split()
+        {
+            let () = VerifySplit::<(), 0>::ASSERTION;
+            let () = VerifySplit::<(u8,), 1>::ASSERTION;
+            let () = VerifySplit::<(u16, u16, u16), 1>::ASSERTION;
+            let () = VerifySplit::<(u32, i32, f32, char), 2>::ASSERTION;
+            let () = VerifySplit::<([u8; 3], [u8; 3], [u8; 3], [u8; 3]), 2>::ASSERTION;
+            let () = VerifySplit::<(u8, u16, u8), 1>::ASSERTION;
+        }
+
+        #[cfg(feature = "array_split")]
+        #[test]
+        fn test_verify_array_split()
+        {
+            use crate::private::{array_split_keeps_order, VerifyArraySplit};
+
+            assert!(array_split_keeps_order::<0, 0>());
+            assert!(array_split_keeps_order::<5, 2>());
+            assert!(array_split_keeps_order::<5, 5>());
+
+            let () = VerifyArraySplit::<300, 123>::ASSERTION;
+        }
+
+        #[cfg(feature = "8")]
+        #[test]
+        fn test_verify_split_8()
+        {
+            let () = VerifySplit::<(u64, u64, u64, u64, u64, u64, u64, u64), 5>::ASSERTION;
+            let () = VerifySplit::<(u8, u16, u32, u64, u128, &str, char, bool), 3>::ASSERTION;
+        }
+
+        #[cfg(all(feature = "16", not(feature = "dont_hurt_yourself_by_using_all_features")))]
+        #[test]
+        fn test_verify_split_16()
+        {
+            let () = VerifySplit::<(u8, (), u64, [u8; 3], u32, f32, f64, i8, i16, i32, i64, i128, u16, u32, u64, u128), 7>::ASSERTION;
+        }
+    }
+
+    /// Every operation on the empty tuple and on 1-tuples, which are the base cases of the macro-generated implementations.
+    mod edge_cases
+    {
+        use crate as tuple_split;
+        use crate::{ConstUsize, ElementPredicate, SplitCow, SplitVisitor, Splitter, TupleElementwiseInto, TupleLen, TupleRefs};
+
+        struct Always;
+
+        impl<T> ElementPredicate<T> for Always
+        {
+            const MATCHES: bool = true;
+        }
+
+        struct Zero;
+
+        impl ConstUsize for Zero
+        {
+            const VALUE: usize = 0;
+        }
+
+        struct Identity;
+
+        impl<L, R> SplitVisitor<L, R> for Identity
+        where
+            L: core::marker::Tuple,
+            R: core::marker::Tuple
+        {
+            type Left = L;
+            type Right = R;
+
+            fn transform_left(&mut self, left: L) -> L
+            {
+                left
+            }
+
+            fn transform_right(&mut self, right: R) -> R
+            {
+                right
+            }
+        }
+
+        #[test]
+        fn test_empty()
+        {
+            let t = ();
+
+            assert_eq!(<()>::LEN, 0);
+            tuple_split::assert_tuple_len::<(), 0>();
+
+            assert_eq!(tuple_split::split_tuple_at::<0, _>(t), ((), ()));
+            assert_eq!(tuple_split::split_tuple_into::<(), ()>(t), ((), ()));
+            assert_eq!(tuple_split::split_tuple_into_left::<(), _>(t), ((), ()));
+            assert_eq!(tuple_split::split_tuple_into_right::<(), _>(t), ((), ()));
+            assert_eq!(tuple_split::split_whole::<(), _, _>(t), ((), ()));
+            #[cfg(feature = "rejoin")]
+            assert_eq!(tuple_split::rejoin_checked::<(), _, _>((), ()), ());
+            assert_eq!(tuple_split::split_tuple_at_const::<Zero, _>(t), ((), ()));
+            assert_eq!(tuple_split::split_at_type_len::<(), _>(t), ((), ()));
+            assert_eq!(tuple_split::split_option_at::<0, _>(Some(t)), (Some(()), Some(())));
+            assert_eq!(tuple_split::split_result_at::<0, _, ()>(Ok(t)), Ok(((), ())));
+            assert_eq!(tuple_split::split_array_of_tuples_at::<0, _, 2>([t, t]), ([(); 2], [(); 2]));
+            assert_eq!(tuple_split::split_halves(t), ((), ()));
+            assert_eq!(tuple_split::split_into_array_halves::<_, u8>(t), [[]; 2]);
+            assert_eq!(tuple_split::take_while_matching::<Always, _>(t), ((), ()));
+            #[cfg(feature = "refs")]
+            assert_eq!(tuple_split::split_tuple_at_ref::<0, _>(&t), ((), ()));
+            #[cfg(feature = "refs")]
+            assert_eq!(tuple_split::split_tuple_at_mut::<0, _>(&mut ()), ((), ()));
+            assert_eq!(
+                tuple_split::split_tuple_at_cow::<0, _>(SplitCow::borrowed(&t)),
+                (SplitCow::Borrowed(()), SplitCow::Borrowed(()))
+            );
+            assert_eq!(tuple_split::split_cyclic::<0, 0, _>(t), ((), ()));
+            assert_eq!(tuple_split::split_map_rebuild::<0, _, _>(t, Identity), ());
+            assert_eq!(Splitter::new(t).at::<0>(), ((), ()));
+
+            let (l, r, rejoin) = tuple_split::split_tuple_at_rejoinable::<0, _>(t);
+            assert_eq!(rejoin(l, r), ());
+
+            type Info = tuple_split::SplitInfo<(), ()>;
+            let _: Info = tuple_split::split_tuple_at_info::<0, _>(t);
+            assert_eq!((Info::LEFT_LEN, Info::RIGHT_LEN, Info::TOTAL_LEN), (0, 0, 0));
+
+            t.elementwise_into();
+            assert_eq!(t.as_refs(), ());
+            assert_eq!(tuple_split::split_tuple_into_left_as::<(), _>(t), ((), ()));
+            assert_eq!(tuple_split::tuple_to_array::<u8, 0, _>(t), []);
+            assert_eq!(tuple_split::array_to_tuple::<[u8; 0]>([]), ());
+            assert_eq!(tuple_split::split_and_pad_left::<0, 0, _, u8>(t), ((), ()));
+            assert_eq!(tuple_split::split_and_pad_left::<0, 1, _, u8>(t), ((0,), ()));
+            assert_eq!(tuple_split::chunks_tuple::<1, _>(t), ());
+            assert_eq!(tuple_split::reverse_tuple(t), ());
+            assert_eq!(tuple_split::reverse_split::<0, _>(t), ((), ()));
+        }
+
+        #[test]
+        fn test_single()
+        {
+            let t = (1u8,);
+
+            assert_eq!(<(u8,)>::LEN, 1);
+            tuple_split::assert_tuple_len::<(u8,), 1>();
+
+            assert_eq!(tuple_split::split_tuple_at::<0, _>(t), ((), t));
+            assert_eq!(tuple_split::split_tuple_at::<1, _>(t), (t, ()));
+            assert_eq!(tuple_split::split_tuple_into::<(u8,), ()>(t), (t, ()));
+            assert_eq!(tuple_split::split_tuple_into::<(), (u8,)>(t), ((), t));
+            assert_eq!(tuple_split::split_tuple_into_left::<(u8,), _>(t), (t, ()));
+            assert_eq!(tuple_split::split_tuple_into_right::<(u8,), _>(t), ((), t));
+            assert_eq!(tuple_split::split_whole::<(), _, _>(t), ((), t));
+            #[cfg(feature = "rejoin")]
+            assert_eq!(tuple_split::rejoin_checked::<(u8,), _, _>((), t), t);
+            #[cfg(feature = "rejoin")]
+            assert_eq!(tuple_split::rejoin_checked::<(u8,), _, _>(t, ()), t);
+            assert_eq!(tuple_split::split_tuple_at_const::<Zero, _>(t), ((), t));
+            assert_eq!(tuple_split::split_at_type_len::<((),), _>(t), (t, ()));
+            assert_eq!(tuple_split::split_tuple_at2::<0, 1, _>(t), ((), t, ()));
+            assert_eq!(tuple_split::split_option_at::<1, (u8,)>(None), (None, None));
+            assert_eq!(tuple_split::split_result_at::<1, _, ()>(Ok(t)), Ok((t, ())));
+            assert_eq!(tuple_split::split_array_of_tuples_at::<1, _, 2>([t, (2,)]), ([t, (2,)], [(); 2]));
+            assert_eq!(tuple_split::split_halves(t), ((), t));
+            assert_eq!(tuple_split::take_while_matching::<Always, _>(t), (t, ()));
+            #[cfg(feature = "refs")]
+            assert_eq!(tuple_split::split_tuple_at_ref::<1, _>(&t), ((&1,), ()));
+            #[cfg(feature = "refs")]
+            assert_eq!(tuple_split::split_tuple_at_mut::<0, _>(&mut (1u8,)), ((), (&mut 1,)));
+            assert_eq!(tuple_split::split_tuple_at_cow::<1, _>(SplitCow::Owned(t)), (SplitCow::Owned(t), SplitCow::Owned(())));
+            assert_eq!(tuple_split::split_cyclic::<1, 1, _>(t), (t, ()));
+            assert_eq!(tuple_split::split_map_rebuild::<1, _, _>(t, Identity), t);
+            assert_eq!(Splitter::new(t).into_left::<(u8,)>(), (t, ()));
+
+            let (l, r, rejoin) = tuple_split::split_tuple_at_rejoinable::<1, _>(t);
+            assert_eq!(rejoin(l, r), t);
+
+            type Info = tuple_split::SplitInfo<(u8,), ()>;
+            let _: Info = tuple_split::split_tuple_at_info::<1, _>(t);
+            assert_eq!((Info::LEFT_LEN, Info::RIGHT_LEN, Info::TOTAL_LEN), (1, 0, 1));
+
+            let u: (u32,) = t.elementwise_into();
+            assert_eq!(u, (1,));
+            assert_eq!(tuple_split::split_tuple_into_left_as::<(u32,), _>(t), ((1,), ()));
+            assert_eq!(tuple_split::tuple_to_array::<u8, 1, _>(t), [1]);
+            assert_eq!(tuple_split::array_to_tuple([1u8]), t);
+            assert_eq!(tuple_split::split_and_pad_left::<1, 2, _, u8>(t), ((1, 0), ()));
+            assert_eq!(tuple_split::chunks_tuple::<1, _>(t), (t,));
+            assert_eq!(tuple_split::chunks_tuple::<2, _>(t), (t,));
+            assert_eq!(tuple_split::reverse_tuple(t), t);
+            assert_eq!(tuple_split::reverse_split::<1, _>(t), (t, ()));
+
+            assert_eq!(tuple_split::split_first(t), (1, ()));
+            assert_eq!(tuple_split::split_last(t), ((), 1));
+        }
+    }
+
+    /// Every split flavour evaluated in a const context, so that a missing `~const` bound anywhere in the chain fails the build.
+    mod const_tests
+    {
+        use crate as tuple_split;
+
+        #[test]
+        fn test_split_tuple_at()
+        {
+            assert_eq!(const { tuple_split::split_tuple_at::<2, _>((1, 2, 3)) }, ((1, 2), (3,)));
+            assert_eq!(const { tuple_split::split_tuple_at::<0, _>(()) }, ((), ()));
+            assert_eq!(const { tuple_split::split_tuple_at::<1, _>((1,)) }, ((1,), ()));
+        }
+
+        #[test]
+        fn test_split_tuple_into_left()
+        {
+            assert_eq!(const { tuple_split::split_tuple_into_left::<(u8, u16), _>((1u8, 2u16, 3u32)) }, ((1, 2), (3,)));
+            assert_eq!(const { tuple_split::split_tuple_into_left::<(), _>((1u8,)) }, ((), (1,)));
+        }
+
+        #[test]
+        fn test_split_tuple_into_right()
+        {
+            assert_eq!(const { tuple_split::split_tuple_into_right::<(u16, u32), _>((1u8, 2u16, 3u32)) }, ((1,), (2, 3)));
+            assert_eq!(const { tuple_split::split_tuple_into_right::<(), _>((1u8,)) }, ((1,), ()));
+        }
+
+        #[test]
+        fn test_other_flavours()
+        {
+            assert_eq!(const { tuple_split::split_tuple_into::<(u8,), (u16, u32)>((1u8, 2u16, 3u32)) }, ((1,), (2, 3)));
+            assert_eq!(const { tuple_split::split_whole((1u8, 2u16)) }, ((1u8,), (2u16,)));
+            assert_eq!(const { tuple_split::split_at_typed::<1, (u8,), (u16,), _>((1u8, 2u16)) }, ((1,), (2,)));
+            assert_eq!(const { tuple_split::split_at_unwrap_single::<1, _>((1u8, 2u16)) }, (1, 2));
+            assert_eq!(const { tuple_split::split_tuple_at2::<1, 2, _>((1u8, 2u16, 3u32)) }, ((1,), (2,), (3,)));
+            assert_eq!(const { tuple_split::split_halves((1, 2, 3, 4)) }, ((1, 2), (3, 4)));
+            assert_eq!(const { tuple_split::split_first::<u8, _>((1u8, 2u16)) }, (1, (2,)));
+            assert_eq!(const { tuple_split::split_last::<u16, _>((1u8, 2u16)) }, ((1,), 2));
+            assert_eq!(const { tuple_split::reverse_split::<1, _>((1, 2, 3)) }, ((3,), (2, 1)));
+            assert_eq!(const { tuple_split::split_option_at::<1, _>(Some((1, 2))) }, (Some((1,)), Some((2,))));
+            assert_eq!(const { tuple_split::zip_split::<1, _, _>((1, 2), (3, 4)) }, (((1,), (3,)), ((2,), (4,))));
+        }
+
+        /// The largest size supported by default. Only built when it's supported, which it isn't with `dont_hurt_yourself_by_using_all_features`.
+        #[cfg(all(feature = "16", not(feature = "dont_hurt_yourself_by_using_all_features")))]
+        mod size_16
+        {
+            use crate as tuple_split;
+
+            type T16 = (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8);
+
+            const T16: T16 = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+            const A16: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+            #[test]
+            fn test_split_tuple_at()
+            {
+                assert_eq!(
+                    const { tuple_split::split_tuple_at::<8, _>(T16) },
+                    ((0, 1, 2, 3, 4, 5, 6, 7), (8, 9, 10, 11, 12, 13, 14, 15))
+                );
+                let (l, r) = const { tuple_split::split_tuple_at::<16, _>(T16) };
+                assert_eq!((tuple_split::tuple_to_array(l), r), (A16, ()));
+            }
+
+            #[test]
+            fn test_split_tuple_into_left()
+            {
+                let (l, r) = const { tuple_split::split_tuple_into_left::<T16, _>(T16) };
+                assert_eq!((tuple_split::tuple_to_array(l), r), (A16, ()));
+            }
+
+            #[test]
+            fn test_split_tuple_into_right()
+            {
+                let (l, r) = const { tuple_split::split_tuple_into_right::<T16, _>(T16) };
+                assert_eq!((l, tuple_split::tuple_to_array(r)), ((), A16));
+            }
+
+            #[test]
+            fn test_split_into_array_halves()
+            {
+                assert_eq!(
+                    const { tuple_split::split_into_array_halves::<_, u8>(T16) },
+                    [[0, 1, 2, 3, 4, 5, 6, 7], [8, 9, 10, 11, 12, 13, 14, 15]]
+                );
+            }
+        }
     }
 }
 
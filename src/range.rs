@@ -0,0 +1,206 @@
+//! Splitting a tuple into more than two parts, or taking elements out of the middle of it.
+//!
+//! This has splits at two indices, like [split_tuple_at2](crate::range::split_tuple_at2), removal of elements, and splits into chunks of a given length.
+//!
+//! # Example
+//!
+//! ```rust
+//! #![feature(generic_const_exprs)]
+//!
+//! let t = (1, 1.0, "test", 'x');
+//!
+//! let (a, b, c) = tuple_split::range::split_tuple_at2::<1, 3, _>(t);
+//! assert_eq!((a, b, c), ((1,), (1.0, "test"), ('x',)));
+//! ```
+
+use tupleops::{ConcatTuples, TupleConcat};
+
+use crate::{private, Left, Right, SplitRange, TupleChunks, TupleLen, TupleRemoveAt, TupleRemoveMany, TupleSplitAt};
+
+/// Splits tuple at two indices, `FIRST` and `SECOND`, into three parts.
+///
+/// The indices are relative to the whole tuple, must be strictly ascending, and can't be past the end of the tuple. Descending, duplicate or out-of-range indices
+/// fail to compile with a dedicated error, rather than silently producing an empty or misplaced middle part, or a confusing error about the halves.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = (1, 1.0, "test", 'c');
+///
+/// let (l, m, r) = tuple_split::split_tuple_at2::<1, 3, _>(t);
+///
+/// assert_eq!(l, (1,));
+/// assert_eq!(m, (1.0, "test"));
+/// assert_eq!(r, ('c',));
+/// ```
+///
+/// ```rust,compile_fail,E0080
+/// #![feature(generic_const_exprs)]
+///
+/// let (l, m, r) = tuple_split::split_tuple_at2::<3, 1, _>((1, 1.0, "test", 'c'));
+/// ```
+///
+/// ```rust,compile_fail,E0080
+/// #![feature(generic_const_exprs)]
+///
+/// let (l, m, r) = tuple_split::split_tuple_at2::<2, 2, _>((1, 1.0, "test", 'c'));
+/// ```
+///
+/// ```rust,compile_fail,E0080
+/// #![feature(generic_const_exprs)]
+///
+/// let (l, m, r) = tuple_split::split_tuple_at2::<1, 5, _>((1u8, 1.0f32, "test", 'c'));
+/// ```
+#[allow(clippy::type_complexity)]
+pub const fn split_tuple_at2<const FIRST: usize, const SECOND: usize, T>(
+    tuple: T
+) -> (
+    T::Left,
+    Left<T::Right, { private::split_span(FIRST, SECOND) }>,
+    Right<T::Right, { private::split_span(FIRST, SECOND) }>
+)
+where
+    T: ~const TupleSplitAt<FIRST, Right: ~const TupleSplitAt<{ private::split_span(FIRST, SECOND) }>> + TupleLen,
+    [(); private::split_index_in_range(SECOND, T::LEN)]:
+{
+    let (left, right) = tuple.split_tuple_at();
+    let (middle, right) = right.split_tuple_at();
+    (left, middle, right)
+}
+
+/// Splits the elements in a [SplitRange](crate::SplitRange) out of a tuple, returning the elements before it, the ones in it, and the ones after it.
+///
+/// Like [split_tuple_at2](crate::split_tuple_at2)`::<START, END, _>`, except that the range may be empty, giving an empty middle part.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// use tuple_split::SplitRange;
+///
+/// let range = SplitRange::<1, 3>::new();
+///
+/// let (l, m, r) = tuple_split::split_range((1, 1.0, "test", 'c'), range);
+///
+/// assert_eq!(l, (1,));
+/// assert_eq!(m, (1.0, "test"));
+/// assert_eq!(r, ('c',));
+///
+/// let (l, m, r) = tuple_split::split_range((1, 1.0, "test", 'c'), SplitRange::<2, 2>::new());
+///
+/// assert_eq!(l, (1, 1.0));
+/// assert_eq!(m, ());
+/// assert_eq!(r, ("test", 'c'));
+/// ```
+#[allow(clippy::type_complexity)]
+pub const fn split_range<const START: usize, const END: usize, T>(
+    tuple: T,
+    _range: SplitRange<START, END>
+) -> (T::Left, Left<T::Right, { END - START }>, Right<T::Right, { END - START }>)
+where
+    T: ~const TupleSplitAt<START, Right: ~const TupleSplitAt<{ END - START }>>
+{
+    let (left, right) = tuple.split_tuple_at();
+    let (middle, right) = right.split_tuple_at();
+    (left, middle, right)
+}
+
+/// Removes the element at index `INDEX` from a tuple, returning the remaining elements and the removed one.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let (rest, removed) = tuple_split::remove_at::<1, _>((1, 1.0, "test"));
+///
+/// assert_eq!(rest, (1, "test"));
+/// assert_eq!(removed, 1.0);
+/// ```
+pub fn remove_at<const INDEX: usize, T>(tuple: T) -> (T::Remaining, T::Removed)
+where
+    T: TupleRemoveAt<INDEX>
+{
+    tuple.remove_at()
+}
+
+/// Removes the elements at each of the indices in `INDICES` from a tuple, returning the remaining elements and a tuple of the removed ones, in order.
+///
+/// The indices are relative to the original tuple, so there's no need to account for each removal shifting the elements after it.
+/// They must be strictly ascending. Descending or duplicate indices fail to compile with a dedicated error.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let (rest, removed) = tuple_split::remove_many::<{ &[0, 2] }, _>((1, 1.0, "test", 'c'));
+///
+/// assert_eq!(rest, (1.0, 'c'));
+/// assert_eq!(removed, (1, "test"));
+/// ```
+///
+/// ```rust,compile_fail,E0080
+/// #![feature(generic_const_exprs)]
+///
+/// let (rest, removed) = tuple_split::remove_many::<{ &[2, 0] }, _>((1, 1.0, "test", 'c'));
+/// ```
+pub fn remove_many<const INDICES: &'static [usize], T>(tuple: T) -> (T::Remaining, T::Removed)
+where
+    T: TupleRemoveMany<INDICES>
+{
+    tuple.remove_many()
+}
+
+/// Splits a tuple into consecutive chunks of `N` elements, returned as a tuple of tuples.
+///
+/// If the length of the tuple isn't divisible by `N`, the last chunk holds the remaining elements, just like with [slice::chunks](slice::chunks).
+/// The empty tuple has no chunks. `N` must be non-zero.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = (1, 1.0, "test", 'c');
+///
+/// let c = tuple_split::chunks_tuple::<3, _>(t);
+///
+/// assert_eq!(c, ((1, 1.0, "test"), ('c',)));
+/// ```
+pub fn chunks_tuple<const N: usize, T>(tuple: T) -> T::Chunks
+where
+    T: TupleChunks<N>
+{
+    tuple.chunks_tuple()
+}
+
+/// Extracts `COUNT` elements starting at index `OFFSET`, wrapping around the end of the tuple if necessary.
+///
+/// Treats the tuple like a ring buffer. Returns the extracted elements, and the remaining elements in the order they follow the extracted ones around the ring.
+/// Every element is moved exactly once, so `COUNT` may not exceed the length of the tuple.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = (1, 2.0, "three", '4');
+///
+/// let (extracted, rest) = tuple_split::split_cyclic::<3, 2, _>(t);
+///
+/// assert_eq!(extracted, ('4', 1));
+/// assert_eq!(rest, (2.0, "three"));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn split_cyclic<const OFFSET: usize, const COUNT: usize, T>(tuple: T) -> (Left<ConcatTuples<T::Right, T::Left>, COUNT>, Right<ConcatTuples<T::Right, T::Left>, COUNT>)
+where
+    T: TupleSplitAt<OFFSET>,
+    (T::Right, T::Left): TupleConcat<T::Right, T::Left, Type: TupleSplitAt<COUNT>>
+{
+    let (left, right) = tuple.split_tuple_at();
+    tupleops::concat_tuples(right, left).split_tuple_at()
+}
@@ -0,0 +1,444 @@
+//! Splitting a tuple and doing something with the halves, like mapping, reversing, padding or converting them.
+//!
+//! This also has conversions between tuples, arrays and iterators, like [tuple_to_array](crate::transform::tuple_to_array) and
+//! [collect_tuple](crate::transform::collect_tuple), which some of the splits are built on.
+//!
+//! # Example
+//!
+//! ```rust
+//! #![feature(generic_const_exprs)]
+//!
+//! let t = (1, 2, 3);
+//!
+//! assert_eq!(tuple_split::transform::reverse_tuple(t), (3, 2, 1));
+//! ```
+
+use tupleops::{Apply, ConcatTuples, MapTuple, TupleApply, TupleConcat, TupleMap, TupleMapper};
+
+use crate::{at::split_halves, private, ArrayToTuple, Left, Padding, Right, SplitError, SplitVisitor, TupleLen, TupleReverse, TupleSplitAt, TupleToArray};
+
+/// Splits tuple at a given index, and maps each element of the left half with a [TupleMapper](tupleops::TupleMapper), using [map_tuple](tupleops::map_tuple).
+///
+/// Returns the mapped left half, and the right half as it is.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// use tupleops::TupleMapper;
+///
+/// struct Wrap;
+///
+/// impl TupleMapper for Wrap
+/// {
+///     type MapElem<Elem> = Option<Elem>;
+///
+///     fn map_elem<Elem>(&mut self, elem: Elem) -> Self::MapElem<Elem>
+///     {
+///         Some(elem)
+///     }
+/// }
+///
+/// let (l, r) = tuple_split::split_and_map_left::<2, _, _>((1, 1.0, "test"), Wrap);
+///
+/// assert_eq!(l, (Some(1), Some(1.0)));
+/// assert_eq!(r, ("test",));
+/// ```
+pub fn split_and_map_left<const MIDDLE: usize, M, T>(tuple: T, mapper: M) -> (MapTuple<M, T::Left>, T::Right)
+where
+    T: TupleSplitAt<MIDDLE>,
+    M: TupleMapper,
+    (M, T::Left): TupleMap<M, T::Left>
+{
+    let (left, right) = tuple.split_tuple_at();
+    (tupleops::map_tuple(mapper, left), right)
+}
+
+/// Splits tuple at a given index, and maps each element of the right half with a [TupleMapper](tupleops::TupleMapper), using [map_tuple](tupleops::map_tuple).
+///
+/// Returns the left half as it is, and the mapped right half.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// use tupleops::TupleMapper;
+///
+/// struct Wrap;
+///
+/// impl TupleMapper for Wrap
+/// {
+///     type MapElem<Elem> = Option<Elem>;
+///
+///     fn map_elem<Elem>(&mut self, elem: Elem) -> Self::MapElem<Elem>
+///     {
+///         Some(elem)
+///     }
+/// }
+///
+/// let (l, r) = tuple_split::split_and_map_right::<2, _, _>((1, 1.0, "test"), Wrap);
+///
+/// assert_eq!(l, (1, 1.0));
+/// assert_eq!(r, (Some("test"),));
+/// ```
+pub fn split_and_map_right<const MIDDLE: usize, M, T>(tuple: T, mapper: M) -> (T::Left, MapTuple<M, T::Right>)
+where
+    T: TupleSplitAt<MIDDLE>,
+    M: TupleMapper,
+    (M, T::Right): TupleMap<M, T::Right>
+{
+    let (left, right) = tuple.split_tuple_at();
+    (left, tupleops::map_tuple(mapper, right))
+}
+
+/// Splits tuple at a given index, and calls a function with the elements of the left half as arguments, using [apply](tupleops::apply).
+///
+/// The function `F` can be `&Fn` or `&mut FnMut`. Returns the result of the call, and the right half as it is.
+///
+/// If `MIDDLE` is `0`, the left half is empty, and [apply](tupleops::apply) doesn't call the function at all.
+/// The result is then `()`, not whatever the function would have returned.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let (sum, r) = tuple_split::split_and_apply_left::<2, _, _>((1, 2, "test"), &|a, b| a + b);
+///
+/// assert_eq!(sum, 3);
+/// assert_eq!(r, ("test",));
+///
+/// let (nothing, r) = tuple_split::split_and_apply_left::<0, _, _>((1, 2, "test"), &|| 3);
+///
+/// assert_eq!(nothing, ());
+/// assert_eq!(r, (1, 2, "test"));
+/// ```
+pub fn split_and_apply_left<const MIDDLE: usize, F, T>(tuple: T, func: F) -> (Apply<F, T::Left>, T::Right)
+where
+    T: TupleSplitAt<MIDDLE>,
+    (F, T::Left): TupleApply<F, T::Left>
+{
+    let (left, right) = tuple.split_tuple_at();
+    (tupleops::apply(func, left), right)
+}
+
+/// Splits tuple at a given index, and also returns a closure which rebuilds the original tuple from the two (possibly modified) halves.
+///
+/// This is useful when splitting, transforming each half, and then joining them back together, without having to name the concrete tuple type.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = (1, 2.0, "test");
+///
+/// let ((a,), (b, c), rejoin) = tuple_split::split_tuple_at_rejoinable::<1, _>(t);
+///
+/// assert_eq!(rejoin((a + 1,), (b * 2.0, c)), (2, 4.0, "test"));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn split_tuple_at_rejoinable<const MIDDLE: usize, T>(tuple: T) -> (Left<T, MIDDLE>, Right<T, MIDDLE>, impl FnOnce(Left<T, MIDDLE>, Right<T, MIDDLE>) -> T)
+where
+    T: TupleSplitAt<MIDDLE>,
+    (T::Left, T::Right): TupleConcat<T::Left, T::Right, Type = T>
+{
+    let (left, right) = tuple.split_tuple_at();
+    (left, right, tupleops::concat_tuples)
+}
+
+/// Turns a homogeneous tuple, where every element is of type `T`, into an array.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let a = tuple_split::tuple_to_array((1, 2, 3));
+///
+/// assert_eq!(a, [1, 2, 3]);
+/// ```
+pub const fn tuple_to_array<T, const N: usize, U>(tuple: U) -> [T; N]
+where
+    U: ~const TupleToArray<T, N>
+{
+    tuple.tuple_to_array()
+}
+
+/// Turns an array into a homogeneous tuple.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = tuple_split::array_to_tuple([1, 2, 3]);
+///
+/// assert_eq!(t, (1, 2, 3));
+/// ```
+pub fn array_to_tuple<A>(array: A) -> A::Tupled
+where
+    A: ArrayToTuple
+{
+    array.array_to_tuple()
+}
+
+/// Splits a homogeneous tuple at a given index, and pads the left half with [Default](core::default::Default) elements until it has `TARGET` elements.
+///
+/// If the left half already has at least `TARGET` elements, it's left as it is. The right half is never touched.
+/// This is useful for normalizing tuples with variable-length prefixes to a fixed shape.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = (1, 2, 3);
+///
+/// let (l, r) = tuple_split::split_and_pad_left::<1, 3, _, _>(t);
+///
+/// assert_eq!(l, (1, 0, 0));
+/// assert_eq!(r, (2, 3));
+/// ```
+pub fn split_and_pad_left<const MIDDLE: usize, const TARGET: usize, T, E>(tuple: T) -> (Padding<E, { private::padded_len(MIDDLE, TARGET) }>, T::Right)
+where
+    T: TupleSplitAt<MIDDLE, Left: TupleToArray<E, MIDDLE>>,
+    E: Default,
+    [E; private::padded_len(MIDDLE, TARGET)]: ArrayToTuple
+{
+    let (left, right) = tuple.split_tuple_at();
+    let mut left = left.tuple_to_array().map(Some);
+    let padded = core::array::from_fn::<E, { private::padded_len(MIDDLE, TARGET) }, _>(|i| left.get_mut(i).and_then(Option::take).unwrap_or_default());
+    (padded.array_to_tuple(), right)
+}
+
+/// Splits a homogeneous tuple of even length in the middle, and turns the two halves into arrays.
+///
+/// This is useful when feeding split data into array-based code, like SIMD or matrices.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let a = tuple_split::split_into_array_halves((1, 2, 3, 4));
+///
+/// assert_eq!(a, [[1, 2], [3, 4]]);
+/// ```
+///
+/// The elements must all be of the same type.
+///
+/// ```rust,compile_fail
+/// #![feature(generic_const_exprs)]
+///
+/// let a = tuple_split::split_into_array_halves((1u8, 2u8, 3u8, 4.0f32));
+/// ```
+pub const fn split_into_array_halves<T, E>(tuple: T) -> [[E; T::LEN / 2]; 2]
+where
+    T: TupleLen + ~const TupleSplitAt<{ T::LEN / 2 }, Left: ~const TupleToArray<E, { T::LEN / 2 }>, Right: ~const TupleToArray<E, { T::LEN / 2 }>>
+{
+    let (left, right) = split_halves(tuple);
+    [left.tuple_to_array(), right.tuple_to_array()]
+}
+
+/// Splits a homogeneous tuple at a given index, and returns an owning iterator over each half.
+///
+/// The iterators are backed by arrays, so nothing is allocated. This lets the halves be piped straight into iterator adapters.
+/// Like array iterators, they can be reversed, and know their exact length.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let (l, r) = tuple_split::split_at_iter::<1, _, _>((1, 2, 3));
+///
+/// assert_eq!(l.sum::<i32>(), 1);
+/// assert_eq!(r.map(|x| x * 10).sum::<i32>(), 50);
+/// ```
+pub fn split_at_iter<const MIDDLE: usize, T, E>(
+    tuple: T
+) -> (
+    impl DoubleEndedIterator<Item = E> + ExactSizeIterator,
+    impl DoubleEndedIterator<Item = E> + ExactSizeIterator
+)
+where
+    T: TupleLen + TupleSplitAt<MIDDLE, Left: TupleToArray<E, MIDDLE>, Right: TupleToArray<E, { T::LEN - MIDDLE }>>
+{
+    let (left, right) = tuple.split_tuple_at();
+    (left.tuple_to_array().into_iter(), right.tuple_to_array().into_iter())
+}
+
+/// Splits a homogeneous tuple at an index only known at runtime, and returns an owning iterator over each half.
+///
+/// Like [split_at_iter](crate::split_at_iter), but returns a [SplitError](crate::SplitError) if `index` is past the end of the tuple.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// use tuple_split::SplitError;
+///
+/// let (l, r) = tuple_split::try_split_at_iter((1, 2, 3), 1).unwrap();
+///
+/// assert!(l.eq([1]));
+/// assert!(r.eq([2, 3]));
+///
+/// assert_eq!(tuple_split::try_split_at_iter((1, 2, 3), 4).err(), Some(SplitError { index: 4, len: 3 }));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn try_split_at_iter<T, E>(tuple: T, index: usize) -> Result<(impl DoubleEndedIterator<Item = E>, impl DoubleEndedIterator<Item = E>), SplitError>
+where
+    T: TupleLen + TupleToArray<E, { T::LEN }>
+{
+    if index > T::LEN
+    {
+        return Err(SplitError { index, len: T::LEN });
+    }
+    let mut left = tuple.tuple_to_array().map(Some);
+    let right: [Option<E>; T::LEN] = core::array::from_fn(|i| {
+        if i >= index
+        {
+            left[i].take()
+        }
+        else
+        {
+            None
+        }
+    });
+    Ok((left.into_iter().flatten(), right.into_iter().flatten()))
+}
+
+/// Collects the first `N` items of an iterator into a homogeneous tuple, or returns [None](Option::None) if it has fewer than `N` items.
+///
+/// Any items after the first `N` are left in the iterator. This is the way back from [split_at_iter](crate::split_at_iter), into tuples.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let t = tuple_split::collect_tuple::<3, _>([1, 2, 3]);
+///
+/// assert_eq!(t, Some((1, 2, 3)));
+/// assert_eq!(tuple_split::collect_tuple::<4, _>([1, 2, 3]), None);
+/// ```
+pub fn collect_tuple<const N: usize, I>(iter: I) -> Option<<[I::Item; N] as ArrayToTuple>::Tupled>
+where
+    I: IntoIterator,
+    [I::Item; N]: ArrayToTuple
+{
+    let mut iter = iter.into_iter();
+    let items = [(); N].map(|()| iter.next());
+    if items.iter().any(Option::is_none)
+    {
+        return None;
+    }
+    Some(items.map(Option::unwrap).array_to_tuple())
+}
+
+/// Reverses the order of the elements in a tuple.
+///
+/// # Example
+///
+/// ```rust
+/// let t = tuple_split::reverse_tuple((1, 1.0, "test"));
+///
+/// assert_eq!(t, ("test", 1.0, 1));
+/// ```
+pub const fn reverse_tuple<T>(tuple: T) -> T::Reversed
+where
+    T: ~const TupleReverse
+{
+    tuple.reverse_tuple()
+}
+
+/// Splits tuple at a given index, and reverses the order of the elements in the right half only.
+///
+/// Unlike [reverse_split](crate::reverse_split), the left half keeps both its elements and their order.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let (l, r) = tuple_split::split_reverse_right::<1, _>((1, 1.0, "test", 'c'));
+///
+/// assert_eq!(l, (1,));
+/// assert_eq!(r, ('c', "test", 1.0));
+/// ```
+pub const fn split_reverse_right<const MIDDLE: usize, T>(tuple: T) -> (T::Left, <T::Right as TupleReverse>::Reversed)
+where
+    T: ~const TupleSplitAt<MIDDLE, Right: ~const TupleReverse>
+{
+    let (left, right) = tuple.split_tuple_at();
+    (left, right.reverse_tuple())
+}
+
+/// Splits tuple at a given index, and reverses the order of the elements in the left half only.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// let (l, r) = tuple_split::split_reverse_left::<3, _>((1, 1.0, "test", 'c'));
+///
+/// assert_eq!(l, ("test", 1.0, 1));
+/// assert_eq!(r, ('c',));
+/// ```
+pub const fn split_reverse_left<const MIDDLE: usize, T>(tuple: T) -> (<T::Left as TupleReverse>::Reversed, T::Right)
+where
+    T: ~const TupleSplitAt<MIDDLE, Left: ~const TupleReverse>
+{
+    let (left, right) = tuple.split_tuple_at();
+    (left.reverse_tuple(), right)
+}
+
+/// Splits tuple at a given index, transforms each half with a [SplitVisitor](crate::SplitVisitor), and joins the transformed halves back into one tuple.
+///
+/// The left half is transformed first.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(generic_const_exprs)]
+///
+/// use tuple_split::SplitVisitor;
+///
+/// struct Swap;
+///
+/// impl SplitVisitor<(u8,), (f32, &'static str)> for Swap
+/// {
+///     type Left = (&'static str,);
+///     type Right = (f32, u8);
+///
+///     fn transform_left(&mut self, (a,): (u8,)) -> Self::Left
+///     {
+///         (if a == 1 { "one" } else { "other" },)
+///     }
+///
+///     fn transform_right(&mut self, (b, _): (f32, &'static str)) -> Self::Right
+///     {
+///         (b, 2)
+///     }
+/// }
+///
+/// let t = tuple_split::split_map_rebuild::<1, _, _>((1u8, 1.0f32, "test"), Swap);
+///
+/// assert_eq!(t, ("one", 1.0, 2));
+/// ```
+pub fn split_map_rebuild<const MIDDLE: usize, V, T>(tuple: T, mut visitor: V) -> ConcatTuples<V::Left, V::Right>
+where
+    T: TupleSplitAt<MIDDLE>,
+    V: SplitVisitor<T::Left, T::Right>,
+    (V::Left, V::Right): TupleConcat<V::Left, V::Right>
+{
+    let (left, right) = tuple.split_tuple_at();
+    let left = visitor.transform_left(left);
+    let right = visitor.transform_right(right);
+    tupleops::concat_tuples(left, right)
+}
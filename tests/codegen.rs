@@ -0,0 +1,78 @@
+//! Functions that split a tuple and concatenate the halves again, which should compile down to nothing.
+//!
+//! Each round-trip function should have the same machine code as the identity function of its size, with optimizations enabled.
+//! The `Codegen` workflow checks this by emitting the assembly with `cargo rustc --release --test codegen -- --emit asm`
+//! and comparing the `#[no_mangle]` symbols below. Here, they're only checked to return their argument.
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+type T3 = (u8, u16, u32);
+#[cfg(feature = "8")]
+type T5 = (u8, u16, u32, u64, u128);
+
+#[no_mangle]
+#[inline(never)]
+pub fn tuple_split_codegen_identity3(t: &T3) -> T3
+{
+    *t
+}
+
+#[no_mangle]
+#[inline(never)]
+pub fn tuple_split_codegen_split_at3(t: &T3) -> T3
+{
+    let (l, r) = tuple_split::split_tuple_at::<1, _>(*t);
+    tupleops::concat_tuples(l, r)
+}
+
+#[no_mangle]
+#[inline(never)]
+pub fn tuple_split_codegen_split_into_left3(t: &T3) -> T3
+{
+    let (l, r) = tuple_split::split_tuple_into_left::<(u8, u16), _>(*t);
+    tupleops::concat_tuples(l, r)
+}
+
+#[no_mangle]
+#[inline(never)]
+pub fn tuple_split_codegen_split_into_right3(t: &T3) -> T3
+{
+    let (l, r) = tuple_split::split_tuple_into_right::<(u16, u32), _>(*t);
+    tupleops::concat_tuples(l, r)
+}
+
+#[cfg(feature = "8")]
+#[no_mangle]
+#[inline(never)]
+pub fn tuple_split_codegen_identity5(t: &T5) -> T5
+{
+    *t
+}
+
+#[cfg(feature = "8")]
+#[no_mangle]
+#[inline(never)]
+pub fn tuple_split_codegen_split_at5(t: &T5) -> T5
+{
+    let (l, r) = tuple_split::split_tuple_at::<3, _>(*t);
+    tupleops::concat_tuples(l, r)
+}
+
+#[test]
+fn split_concat_round_trips()
+{
+    let t = (1, 2, 3);
+    assert_eq!(tuple_split_codegen_identity3(&t), t);
+    assert_eq!(tuple_split_codegen_split_at3(&t), t);
+    assert_eq!(tuple_split_codegen_split_into_left3(&t), t);
+    assert_eq!(tuple_split_codegen_split_into_right3(&t), t);
+}
+
+#[cfg(feature = "8")]
+#[test]
+fn split_concat_round_trips_5()
+{
+    let t = (1, 2, 3, 4, 5);
+    assert_eq!(tuple_split_codegen_identity5(&t), t);
+    assert_eq!(tuple_split_codegen_split_at5(&t), t);
+}
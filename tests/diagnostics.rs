@@ -0,0 +1,14 @@
+//! Checks the wording of the compile errors given when a tuple can't be split, against the `.stderr` files in `tests/ui`.
+//!
+//! The errors list other tuples which can be split, so they depend on the size feature. They're only checked with the default size.
+//! If the wording changes on purpose, run `TRYBUILD=overwrite cargo test --test diagnostics` to update the expected errors.
+#![cfg(all(feature = "16", not(feature = "32"), not(feature = "dont_hurt_yourself_by_using_all_features")))]
+
+#[test]
+fn diagnostics()
+{
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+    #[cfg(feature = "rejoin")]
+    t.compile_fail("tests/ui/rejoin/*.rs");
+}
@@ -0,0 +1,89 @@
+//! Checks that splitting a small tuple gives the same results no matter which size feature is enabled.
+//!
+//! The impls are generated once for the largest enabled size, picked by a ladder of `#[cfg]`s, so every size feature goes through a different path.
+//! This only means something when run under each of them, like with `cargo test --test features --no-default-features --features 8`,
+//! and the same for `16`, `32` and so on, with and without `dont_hurt_yourself_by_using_all_features`.
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+use tuple_split::TupleLen;
+
+type T3 = (u8, &'static str, f32);
+
+const T3: T3 = (1, "two", 3.0);
+
+#[test]
+fn small_tuple_splits_agree()
+{
+    assert_eq!(tuple_split::split_tuple_at::<0, _>(T3), ((), T3));
+    assert_eq!(tuple_split::split_tuple_at::<1, _>(T3), ((1,), ("two", 3.0)));
+    assert_eq!(tuple_split::split_tuple_at::<2, _>(T3), ((1, "two"), (3.0,)));
+    assert_eq!(tuple_split::split_tuple_at::<3, _>(T3), (T3, ()));
+
+    assert_eq!(tuple_split::split_tuple_into_left::<(u8,), _>(T3), ((1,), ("two", 3.0)));
+    assert_eq!(tuple_split::split_tuple_into_right::<(f32,), _>(T3), ((1, "two"), (3.0,)));
+    #[cfg(feature = "refs")]
+    assert_eq!(tuple_split::split_tuple_at_ref::<1, _>(&T3), ((&1,), (&"two", &3.0)));
+
+    assert_eq!(<T3>::LEN, 3);
+}
+
+/// The smallest size any combination of features supports, which is also what's supported without any size feature at all.
+#[test]
+fn smallest_size_is_supported()
+{
+    let t = (1u8, 2u16, 3u32, 4u64);
+
+    assert_eq!(tuple_split::split_tuple_at::<2, _>(t), ((1, 2), (3, 4)));
+    assert_eq!(<(u8, u16, u32, u64)>::LEN, 4);
+}
+
+#[cfg(feature = "8")]
+#[test]
+fn size_8_is_supported()
+{
+    let t = (1u8, 2u16, 3u32, 4u64, 5i8, 6i16, 7i32, 8i64);
+
+    assert_eq!(tuple_split::split_tuple_at::<3, _>(t), ((1, 2, 3), (4, 5, 6, 7, 8)));
+    assert_eq!(tuple_split::split_tuple_at::<8, _>(t), (t, ()));
+}
+
+#[cfg(all(feature = "16", not(feature = "dont_hurt_yourself_by_using_all_features")))]
+#[test]
+fn size_16_is_supported()
+{
+    let t = (0u8, 1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, 12u8, 13u8, 14u8, 15u8);
+
+    let (l, r) = tuple_split::split_tuple_at::<10, _>(t);
+    assert_eq!(l, (0, 1, 2, 3, 4, 5, 6, 7, 8, 9));
+    assert_eq!(r, (10, 11, 12, 13, 14, 15));
+}
+
+/// Which size is supported is decided by a `#[cfg]` ladder, so this checks it against the enabled features in another way.
+#[test]
+fn max_tuple_size_matches_features()
+{
+    let sizes = [
+        (cfg!(feature = "8"), 8),
+        (cfg!(feature = "16"), 16),
+        (cfg!(feature = "32"), 32),
+        (cfg!(feature = "64"), 64),
+        (cfg!(feature = "96"), 96),
+        (cfg!(feature = "128"), 128),
+        (cfg!(feature = "160"), 160),
+        (cfg!(feature = "192"), 192),
+        (cfg!(feature = "224"), 224),
+        (cfg!(feature = "256"), 256)
+    ];
+    let largest = sizes.into_iter().filter(|&(enabled, _)| enabled).map(|(_, size)| size).max().unwrap_or(4);
+    let expected = if cfg!(feature = "dont_hurt_yourself_by_using_all_features")
+    {
+        largest.min(8)
+    }
+    else
+    {
+        largest
+    };
+
+    assert_eq!(tuple_split::MAX_TUPLE_SIZE, expected);
+}
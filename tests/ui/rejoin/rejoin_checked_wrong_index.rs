@@ -0,0 +1,8 @@
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+fn main()
+{
+    let (l, r) = tuple_split::split_tuple_at::<1, _>((1u8, 1.0f32, "test"));
+    let _ = tuple_split::rejoin_checked::<(u8, f32, &str, bool), _, _>(l, r);
+}
@@ -0,0 +1,7 @@
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+fn main()
+{
+    let _ = tuple_split::split_tuple_at2::<2, 2, _>((1u8, 1.0f32, "test", 'c'));
+}
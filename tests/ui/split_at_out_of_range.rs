@@ -0,0 +1,9 @@
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+use tuple_split::TupleSplitAt;
+
+fn main()
+{
+    let _ = TupleSplitAt::<4>::split_tuple_at((1u8, 1.0f32, "test"));
+}
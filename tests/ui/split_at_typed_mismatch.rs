@@ -0,0 +1,7 @@
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+fn main()
+{
+    let _ = tuple_split::split_at_typed::<2, (u8, f32), (&str,), _>((1.0f32, 1u8, "test"));
+}